@@ -147,6 +147,27 @@ where
     pub fn push(&mut self, val: T){
         *self.counter.entry(val).or_insert(0) += 1;
     }
+
+    /**
+    Removes one occurrence of the element and returns the remaining count.
+
+    Elements with a count of 0 are removed, so ```len()``` is always the number of distinct elements.
+    Removing an element that is not present does nothing,
+    an element with a count of 0 (set through ```DerefMut``` or ```from_hashmap()```) is treated as not present and removed.
+    */
+    pub fn remove_one(&mut self, val: &T) -> usize {
+        let remaining = match self.counter.get_mut(val) {
+            None => return 0,
+            Some(count) => {
+                *count = count.saturating_sub(1);
+                *count
+            }
+        };
+        if remaining == 0 {
+            self.counter.remove(val);
+        }
+        remaining
+    }
 }
 
 impl<T, S> Default for Counter<T, S>
@@ -491,5 +512,18 @@ mod tests {
 
     }
 
+    #[test]
+    fn remove_one() {
+        let mut cnt: Counter<char> = Counter::from_iter("abba".chars());
+        assert_eq!(cnt.remove_one(&'a'), 1);
+        assert_eq!(cnt.remove_one(&'a'), 0);
+        assert_eq!(cnt.remove_one(&'a'), 0);
+        assert_eq!(cnt.len(), 1);
+        assert_eq!(cnt.remove_one(&'c'), 0);
+        cnt.insert('z', 0);
+        assert_eq!(cnt.remove_one(&'z'), 0);
+        assert_eq!(cnt.len(), 1);
+    }
+
 
 }
\ No newline at end of file
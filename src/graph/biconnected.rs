@@ -0,0 +1,423 @@
+use super::{Adjacency, Graph, connected_components};
+
+//results of the depth-first search used by all algorithms of this module
+struct LowLink {
+    //entry time of every vertex
+    tin: Vec<usize>,
+    //the earliest entry time reachable from the subtree using at most one non-tree edge
+    low: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    parent_edge: Vec<Option<usize>>,
+    //vertices in the order of visiting
+    order: Vec<usize>,
+}
+
+//an iterative depth-first search, only the edge used to enter a vertex is not followed back
+fn low_link<W>(graph: &Graph<W>) -> LowLink {
+    let n = graph.vertex_count();
+    let mut tin: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0usize; n];
+    let mut parent = vec![None; n];
+    let mut parent_edge = vec![None; n];
+    let mut order = Vec::with_capacity(n);
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    for root in 0..n {
+        if tin[root].is_some() {
+            continue;
+        }
+        tin[root] = Some(order.len());
+        low[root] = order.len();
+        order.push(root);
+        stack.push((root, 0));
+        while let Some(&(v, pos)) = stack.last() {
+            let neighbors = graph.neighbors(v);
+            if pos < neighbors.len() {
+                let top = stack.len() - 1;
+                stack[top].1 += 1;
+                let u = neighbors[pos];
+                let id = graph.edge_ids(v)[pos];
+                if parent_edge[v] == Some(id) {
+                    continue;
+                }
+                match tin[u] {
+                    None => {
+                        tin[u] = Some(order.len());
+                        low[u] = order.len();
+                        order.push(u);
+                        parent[u] = Some(v);
+                        parent_edge[u] = Some(id);
+                        stack.push((u, 0));
+                    }
+                    Some(t) => low[v] = low[v].min(t),
+                }
+                continue;
+            }
+            stack.pop();
+            if let Some(p) = parent[v] {
+                low[p] = low[p].min(low[v]);
+            }
+        }
+    }
+    LowLink {
+        tin: tin.into_iter().map(|t| t.unwrap()).collect(),
+        low,
+        parent,
+        parent_edge,
+        order,
+    }
+}
+
+//ends of every edge of the graph
+fn endpoints<W>(graph: &Graph<W>) -> Vec<(usize, usize)> {
+    let mut ends = vec![(0, 0); graph.edge_count()];
+    for v in 0..graph.vertex_count() {
+        for (&u, &id) in graph.neighbors(v).iter().zip(graph.edge_ids(v)) {
+            if v <= u {
+                ends[id] = (v, u);
+            }
+        }
+    }
+    ends
+}
+
+/**
+Finds bridges of an undirected graph: edges whose removal increases the number of connected components.
+
+Returns indexes of bridges in the increasing order. Parallel edges are never bridges.
+The depth-first search is iterative, so the function works for graphs of any depth.
+
+**More:** <https://en.wikipedia.org/wiki/Bridge_(graph_theory)#Tarjan's_bridge-finding_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, bridges};
+
+fn main(){
+    //two triangles connected with an edge
+    let graph = Graph::new(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(bridges(&graph), vec![3]);
+}
+```
+*/
+pub fn bridges<W>(graph: &Graph<W>) -> Vec<usize> {
+    let links = low_link(graph);
+    let mut found: Vec<usize> = (0..graph.vertex_count()).filter_map(|v| match (links.parent[v], links.parent_edge[v]) {
+        (Some(p), Some(id)) if links.low[v] > links.tin[p] => Some(id),
+        _ => None,
+    }).collect();
+    found.sort();
+    found
+}
+
+/**
+Finds articulation points (cut vertices) of an undirected graph: vertices whose removal increases the number of connected components.
+
+Returns articulation points in the increasing order.
+The depth-first search is iterative, so the function works for graphs of any depth.
+
+**More:** <https://en.wikipedia.org/wiki/Biconnected_component#Algorithms>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, articulation_points};
+
+fn main(){
+    //two triangles sharing vertex 2 and a pendant vertex 5
+    let graph = Graph::new(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+    assert_eq!(articulation_points(&graph), vec![2, 4]);
+}
+```
+*/
+pub fn articulation_points<W>(graph: &Graph<W>) -> Vec<usize> {
+    let n = graph.vertex_count();
+    let links = low_link(graph);
+    let mut is_cut = vec![false; n];
+    let mut root_children = vec![0usize; n];
+    for v in 0..n {
+        if let Some(p) = links.parent[v] {
+            if links.parent[p].is_none() {
+                root_children[p] += 1;
+            } else if links.low[v] >= links.tin[p] {
+                is_cut[p] = true;
+            }
+        }
+    }
+    //a root is an articulation point if it has more than one child in the search tree
+    (0..n).filter(|&v| is_cut[v] || root_children[v] > 1).collect()
+}
+
+/**
+Finds 2-edge-connected components of an undirected graph: maximal sets of vertices that stay connected after removing any single edge.
+
+Returns the number of components and the component index of every vertex.
+Components are numbered in the order of their smallest vertices, they are connected components of the graph without bridges.
+
+**More:** <https://en.wikipedia.org/wiki/K-edge-connected_graph>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, two_edge_connected_components};
+
+fn main(){
+    let graph = Graph::new(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(two_edge_connected_components(&graph), (2, vec![0, 0, 0, 1, 1, 1]));
+}
+```
+*/
+pub fn two_edge_connected_components<W>(graph: &Graph<W>) -> (usize, Vec<usize>) {
+    let mut is_bridge = vec![false; graph.edge_count()];
+    for id in bridges(graph) {
+        is_bridge[id] = true;
+    }
+    let remaining: Vec<(usize, usize)> = endpoints(graph).into_iter().enumerate()
+        .filter(|&(id, _)| !is_bridge[id]).map(|(_, ends)| ends).collect();
+    connected_components(&Graph::new(graph.vertex_count(), &remaining))
+}
+
+/**
+Finds biconnected components (blocks) of an undirected graph: maximal subgraphs that stay connected after removing any single vertex.
+
+Every edge except loops belongs to exactly one block. Returns indexes of edges of every block in the increasing order.
+Two blocks can share only one vertex, which is an articulation point.
+The depth-first search is iterative, so the function works for graphs of any depth.
+
+**More:** <https://en.wikipedia.org/wiki/Biconnected_component>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, biconnected_components};
+
+fn main(){
+    let graph = Graph::new(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+    assert_eq!(biconnected_components(&graph), vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+}
+```
+*/
+pub fn biconnected_components<W>(graph: &Graph<W>) -> Vec<Vec<usize>> {
+    let n = graph.vertex_count();
+    let links = low_link(graph);
+    //block of the tree edge entering every vertex
+    let mut block_in: Vec<Option<usize>> = vec![None; n];
+    let mut count = 0;
+    for &v in &links.order {
+        if let Some(p) = links.parent[v] {
+            block_in[v] = match block_in[p] {
+                Some(b) if links.low[v] < links.tin[p] => Some(b),
+                _ => {
+                    count += 1;
+                    Some(count - 1)
+                }
+            };
+        }
+    }
+    //every edge other than a loop belongs to the block of the tree edge entering its deeper end
+    let mut blocks = vec![Vec::new(); count];
+    for v in 0..n {
+        for (&u, &id) in graph.neighbors(v).iter().zip(graph.edge_ids(v)) {
+            if links.tin[v] > links.tin[u] {
+                blocks[block_in[v].unwrap()].push(id);
+            }
+        }
+    }
+    for block in &mut blocks {
+        block.sort();
+    }
+    blocks
+}
+
+/**
+Block-cut tree of an undirected graph, created by ```block_cut_tree()```.
+*/
+#[derive(Clone, Debug)]
+pub struct BlockCutTree {
+    ///Vertices of every block in the increasing order, isolated vertices form single-vertex blocks.
+    pub blocks: Vec<Vec<usize>>,
+    ///Articulation points in the increasing order.
+    pub cut_vertices: Vec<usize>,
+    ///Forest connecting blocks (vertices from 0 to ```blocks.len() - 1```) with cut vertices they contain
+    ///(vertex ```blocks.len() + i``` represents ```cut_vertices[i]```).
+    pub tree: Graph,
+}
+
+/**
+Creates the block-cut tree of an undirected graph, which describes how biconnected components are joined by articulation points.
+
+Blocks are listed in the same order as in ```biconnected_components()```, followed by isolated vertices.
+Every connected component of the graph corresponds to one tree of the forest.
+
+**More:** <https://en.wikipedia.org/wiki/Biconnected_component#Block-cut_tree>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, Adjacency, block_cut_tree};
+
+fn main(){
+    let graph = Graph::new(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+    let tree = block_cut_tree(&graph);
+    assert_eq!(tree.blocks, vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5]]);
+    assert_eq!(tree.cut_vertices, vec![2, 4]);
+    //the block in the middle is connected with both cut vertices
+    assert_eq!(tree.tree.neighbors(1), &[3, 4]);
+}
+```
+*/
+pub fn block_cut_tree<W>(graph: &Graph<W>) -> BlockCutTree {
+    let n = graph.vertex_count();
+    let ends = endpoints(graph);
+    let mut in_block = vec![false; n];
+    let mut blocks: Vec<Vec<usize>> = biconnected_components(graph).into_iter().map(|edges| {
+        let mut vertices: Vec<usize> = edges.iter().flat_map(|&id| vec![ends[id].0, ends[id].1]).collect();
+        vertices.sort();
+        vertices.dedup();
+        for &v in &vertices {
+            in_block[v] = true;
+        }
+        vertices
+    }).collect();
+    blocks.extend((0..n).filter(|&v| !in_block[v]).map(|v| vec![v]));
+    let cut_vertices = articulation_points(graph);
+    let mut cut_index = vec![None; n];
+    for (i, &v) in cut_vertices.iter().enumerate() {
+        cut_index[v] = Some(i);
+    }
+    let mut edges = Vec::new();
+    for (b, block) in blocks.iter().enumerate() {
+        for &v in block {
+            if let Some(i) = cut_index[v] {
+                edges.push((b, blocks.len() + i));
+            }
+        }
+    }
+    let tree = Graph::new(blocks.len() + cut_vertices.len(), &edges);
+    BlockCutTree {
+        blocks,
+        cut_vertices,
+        tree,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::has_cycle;
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    fn random_graph(rng: &mut XorShiftRng) -> (usize, Vec<(usize, usize)>) {
+        let n = rng.gen_range(1, 15);
+        let m = rng.gen_range(0, 2 * n);
+        (n, (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n))).collect())
+    }
+
+    //number of components among vertices that are not removed
+    fn components_without(n: usize, edges: &[(usize, usize)], removed: Option<usize>) -> usize {
+        let kept: Vec<(usize, usize)> = edges.iter().cloned().filter(|&(a, b)| Some(a) != removed && Some(b) != removed).collect();
+        connected_components(&Graph::new(n, &kept)).0 - if removed.is_some() { 1 } else { 0 }
+    }
+
+    #[test]
+    fn brute_force() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..200 {
+            let (n, edges) = random_graph(&mut rng);
+            let graph = Graph::new(n, &edges);
+            let before = components_without(n, &edges, None);
+            let expected_bridges: Vec<usize> = (0..edges.len()).filter(|&id| {
+                let mut rest = edges.clone();
+                rest.remove(id);
+                components_without(n, &rest, None) > before
+            }).collect();
+            assert_eq!(bridges(&graph), expected_bridges);
+            let expected_points: Vec<usize> = (0..n).filter(|&v| components_without(n, &edges, Some(v)) > before).collect();
+            assert_eq!(articulation_points(&graph), expected_points);
+            //vertices stay connected after removing any edge
+            let mut labels = vec![connected_components(&graph).1];
+            for id in 0..edges.len() {
+                let mut rest = edges.clone();
+                rest.remove(id);
+                labels.push(connected_components(&Graph::new(n, &rest)).1);
+            }
+            let (_, component) = two_edge_connected_components(&graph);
+            for a in 0..n {
+                for b in 0..n {
+                    assert_eq!(component[a] == component[b], labels.iter().all(|c| c[a] == c[b]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn blocks() {
+        let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+        for _ in 0..200 {
+            let (n, edges) = random_graph(&mut rng);
+            let graph = Graph::new(n, &edges);
+            let blocks = biconnected_components(&graph);
+            //blocks divide edges other than loops
+            let mut all: Vec<usize> = blocks.iter().flat_map(|b| b.clone()).collect();
+            all.sort();
+            let expected: Vec<usize> = (0..edges.len()).filter(|&id| edges[id].0 != edges[id].1).collect();
+            assert_eq!(all, expected);
+            //every block is connected and has no articulation points
+            for block in &blocks {
+                let mut label: Vec<usize> = block.iter().flat_map(|&id| vec![edges[id].0, edges[id].1]).collect();
+                label.sort();
+                label.dedup();
+                let sub: Vec<(usize, usize)> = block.iter().map(|&id| {
+                    (label.binary_search(&edges[id].0).unwrap(), label.binary_search(&edges[id].1).unwrap())
+                }).collect();
+                let sub = Graph::new(label.len(), &sub);
+                assert_eq!(connected_components(&sub).0, 1);
+                assert!(articulation_points(&sub).is_empty());
+            }
+            let tree = block_cut_tree(&graph);
+            assert_eq!(tree.cut_vertices, articulation_points(&graph));
+            assert!(!has_cycle(&tree.tree));
+            assert_eq!(connected_components(&tree.tree).0, connected_components(&graph).0);
+        }
+    }
+
+    #[test]
+    fn deep() {
+        let n = 200_000;
+        let mut edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+        let path = Graph::new(n, &edges);
+        assert_eq!(bridges(&path).len(), n - 1);
+        assert_eq!(articulation_points(&path).len(), n - 2);
+        edges.push((n - 1, 0));
+        let cycle = Graph::new(n, &edges);
+        assert!(bridges(&cycle).is_empty());
+        assert!(articulation_points(&cycle).is_empty());
+        assert_eq!(biconnected_components(&cycle).len(), 1);
+        assert_eq!(two_edge_connected_components(&cycle).0, 1);
+    }
+}
@@ -0,0 +1,161 @@
+use super::{Adjacency, Graph};
+
+/**
+Finds connected components of an undirected graph.
+
+Returns the number of components and the component index of every vertex.
+Components are numbered in the order of their smallest vertices.
+
+**More:** <https://en.wikipedia.org/wiki/Component_(graph_theory)>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, connected_components};
+
+fn main(){
+    let graph = Graph::new(6, &[(0, 3), (1, 2), (3, 5)]);
+    assert_eq!(connected_components(&graph), (3, vec![0, 1, 1, 0, 2, 0]));
+}
+```
+*/
+pub fn connected_components<W>(graph: &Graph<W>) -> (usize, Vec<usize>) {
+    let n = graph.vertex_count();
+    let mut component: Vec<Option<usize>> = vec![None; n];
+    let mut count = 0;
+    let mut stack = Vec::new();
+    for root in 0..n {
+        if component[root].is_some() {
+            continue;
+        }
+        component[root] = Some(count);
+        stack.push(root);
+        while let Some(v) = stack.pop() {
+            for &u in graph.neighbors(v) {
+                if component[u].is_none() {
+                    component[u] = Some(count);
+                    stack.push(u);
+                }
+            }
+        }
+        count += 1;
+    }
+    (count, component.into_iter().map(|c| c.unwrap()).collect())
+}
+
+/**
+Splits vertices of an undirected graph into two sides, so that every edge connects different sides.
+
+Returns the side of every vertex (```false``` for the smallest vertex of every component)
+or None if the graph is not bipartite, i.e. it contains a cycle of odd length.
+
+**More:** <https://en.wikipedia.org/wiki/Bipartite_graph>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, bipartition};
+
+fn main(){
+    let square = Graph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    assert_eq!(bipartition(&square), Some(vec![false, true, false, true]));
+    let triangle = Graph::new(3, &[(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(bipartition(&triangle), None);
+}
+```
+*/
+pub fn bipartition<W>(graph: &Graph<W>) -> Option<Vec<bool>> {
+    let n = graph.vertex_count();
+    let mut side: Vec<Option<bool>> = vec![None; n];
+    let mut stack = Vec::new();
+    for root in 0..n {
+        if side[root].is_some() {
+            continue;
+        }
+        side[root] = Some(false);
+        stack.push(root);
+        while let Some(v) = stack.pop() {
+            let other = side[v].map(|s| !s);
+            for &u in graph.neighbors(v) {
+                match side[u] {
+                    None => {
+                        side[u] = other;
+                        stack.push(u);
+                    }
+                    Some(s) => if Some(s) != other {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+    Some(side.into_iter().map(|s| s.unwrap()).collect())
+}
+
+/**
+Checks if an undirected graph is bipartite, see ```bipartition()```.
+
+**More:** <https://en.wikipedia.org/wiki/Bipartite_graph>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+*/
+pub fn is_bipartite<W>(graph: &Graph<W>) -> bool {
+    bipartition(graph).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use collections::FastDisjointSet;
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    #[test]
+    fn matches_disjoint_set() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 40);
+            let m = rng.gen_range(0, 40);
+            let edges: Vec<(usize, usize)> = (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n))).collect();
+            let (count, component) = connected_components(&Graph::new(n, &edges));
+            let mut ds: FastDisjointSet<usize> = (0..n).collect();
+            for &(a, b) in &edges {
+                ds.union(a, b);
+            }
+            for a in 0..n {
+                for b in 0..n {
+                    assert_eq!(component[a] == component[b], ds.in_union(&a, &b));
+                }
+            }
+            assert_eq!(count, (0..n).filter(|&v| (0..v).all(|u| !ds.in_union(&u, &v))).count());
+        }
+    }
+
+    #[test]
+    fn bipartite() {
+        //even cycle with a pendant vertex and a separate edge
+        let graph = Graph::new(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (2, 6)]);
+        let side = bipartition(&graph).unwrap();
+        for (v, &s) in side.iter().enumerate().take(6) {
+            assert_eq!(s, v % 2 == 1);
+        }
+        assert!(side[6]);
+        //loops are odd cycles
+        assert!(!is_bipartite(&Graph::new(2, &[(0, 1), (1, 1)])));
+        assert!(is_bipartite(&Graph::new(3, &[])));
+        //parallel edges are allowed
+        assert!(is_bipartite(&Graph::new(2, &[(0, 1), (1, 0)])));
+    }
+}
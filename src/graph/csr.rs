@@ -0,0 +1,299 @@
+/**
+Read access to adjacency lists of a graph, implemented by ```Graph``` and ```DiGraph```.
+
+Vertices are numbered from 0 to ```vertex_count() - 1```. Neighbours of a vertex together with weights and
+indexes of the corresponding edges in the list used to create the graph are available as parallel slices.
+*/
+pub trait Adjacency {
+    type Weight;
+
+    ///Number of vertices.
+    fn vertex_count(&self) -> usize;
+
+    ///Number of edges in the list used to create the graph.
+    fn edge_count(&self) -> usize;
+
+    ///Checks if edges are directed.
+    fn is_directed(&self) -> bool;
+
+    ///Targets of edges leaving the vertex.
+    fn neighbors(&self, v: usize) -> &[usize];
+
+    ///Weights of edges leaving the vertex, in the same order as ```neighbors()```.
+    fn weights(&self, v: usize) -> &[Self::Weight];
+
+    ///Indexes of edges leaving the vertex in the list used to create the graph, in the same order as ```neighbors()```.
+    fn edge_ids(&self, v: usize) -> &[usize];
+
+    ///Number of edges leaving the vertex.
+    fn degree(&self, v: usize) -> usize {
+        self.neighbors(v).len()
+    }
+}
+
+//compressed sparse row representation shared by both graph types
+#[derive(Clone, Debug)]
+struct Csr<W> {
+    //adjacency list of v is stored in [offsets[v], offsets[v+1])
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<W>,
+    edge_ids: Vec<usize>,
+    edge_count: usize,
+}
+
+impl<W> Csr<W> {
+    #[inline(always)]
+    fn range(&self, v: usize) -> ::std::ops::Range<usize> {
+        self.offsets[v]..self.offsets[v + 1]
+    }
+}
+
+impl<W> Csr<W> where W: Clone {
+    //undirected edges are stored in both directions
+    fn new(vertices: usize, edges: &[(usize, usize, W)], directed: bool) -> Self {
+        let mut offsets = vec![0usize; vertices + 1];
+        for &(from, to, _) in edges {
+            assert!(from < vertices && to < vertices, "Edge ({}, {}) goes beyond {} vertices", from, to, vertices);
+            offsets[from + 1] += 1;
+            if !directed {
+                offsets[to + 1] += 1;
+            }
+        }
+        for v in 0..vertices {
+            offsets[v + 1] += offsets[v];
+        }
+        let total = offsets[vertices];
+        let mut next = offsets.clone();
+        let mut targets = vec![0usize; total];
+        let mut weights: Vec<Option<W>> = vec![None; total];
+        let mut edge_ids = vec![0usize; total];
+        for (id, &(from, to, ref weight)) in edges.iter().enumerate() {
+            let arcs = if directed { 1 } else { 2 };
+            for &(a, b) in [(from, to), (to, from)].iter().take(arcs) {
+                let pos = next[a];
+                next[a] += 1;
+                targets[pos] = b;
+                weights[pos] = Some(weight.clone());
+                edge_ids[pos] = id;
+            }
+        }
+        Csr {
+            offsets,
+            targets,
+            weights: weights.into_iter().map(|w| w.unwrap()).collect(),
+            edge_ids,
+            edge_count: edges.len(),
+        }
+    }
+}
+
+fn unweighted(edges: &[(usize, usize)]) -> Vec<(usize, usize, ())> {
+    edges.iter().map(|&(from, to)| (from, to, ())).collect()
+}
+
+/**
+Undirected graph stored in the compressed sparse row format.
+
+Every edge is stored in adjacency lists of both its ends (a loop is stored twice in the list of its vertex).
+The graph is immutable, adjacency lists are accessed through the ```Adjacency``` trait.
+
+**More:** <https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)>
+
+# Complexity
+
+- Creation complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, Adjacency};
+
+fn main(){
+    let graph = Graph::new(4, &[(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(graph.neighbors(0), &[1, 2]);
+    assert_eq!(graph.degree(3), 0);
+
+    let roads = Graph::with_weights(3, &[(0, 1, 5.0), (1, 2, 2.5)]);
+    assert_eq!(roads.weights(1), &[5.0, 2.5]);
+    assert_eq!(roads.edge_ids(1), &[0, 1]);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct Graph<W = ()> {
+    csr: Csr<W>,
+}
+
+impl Graph<()> {
+    /**
+    Creates an unweighted graph from the list of edges.
+
+    Panics if any edge goes beyond the given number of vertices.
+    */
+    pub fn new(vertices: usize, edges: &[(usize, usize)]) -> Self {
+        Graph::with_weights(vertices, &unweighted(edges))
+    }
+}
+
+impl<W> Graph<W> where W: Clone {
+    /**
+    Creates a weighted graph from the list of edges.
+
+    Panics if any edge goes beyond the given number of vertices.
+    */
+    pub fn with_weights(vertices: usize, edges: &[(usize, usize, W)]) -> Self {
+        Graph {
+            csr: Csr::new(vertices, edges, false),
+        }
+    }
+}
+
+/**
+Directed graph stored in the compressed sparse row format.
+
+The graph is immutable, adjacency lists of outgoing edges are accessed through the ```Adjacency``` trait.
+
+**More:** <https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)>
+
+# Complexity
+
+- Creation complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, Adjacency};
+
+fn main(){
+    let graph = DiGraph::new(3, &[(0, 1), (1, 2), (2, 0), (0, 2)]);
+    assert_eq!(graph.neighbors(0), &[1, 2]);
+    assert_eq!(graph.edge_ids(0), &[0, 3]);
+    assert_eq!(graph.reversed().neighbors(0), &[2]);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct DiGraph<W = ()> {
+    csr: Csr<W>,
+}
+
+impl DiGraph<()> {
+    /**
+    Creates an unweighted directed graph from the list of edges.
+
+    Panics if any edge goes beyond the given number of vertices.
+    */
+    pub fn new(vertices: usize, edges: &[(usize, usize)]) -> Self {
+        DiGraph::with_weights(vertices, &unweighted(edges))
+    }
+}
+
+impl<W> DiGraph<W> where W: Clone {
+    /**
+    Creates a weighted directed graph from the list of edges.
+
+    Panics if any edge goes beyond the given number of vertices.
+    */
+    pub fn with_weights(vertices: usize, edges: &[(usize, usize, W)]) -> Self {
+        DiGraph {
+            csr: Csr::new(vertices, edges, true),
+        }
+    }
+
+    /**
+    Creates a graph with all edges reversed, edge indexes are preserved.
+
+    **Complexity:** O(V + E)
+    */
+    pub fn reversed(&self) -> Self {
+        let mut slots: Vec<Option<(usize, usize, W)>> = vec![None; self.csr.edge_count];
+        for v in 0..self.vertex_count() {
+            for pos in self.csr.range(v) {
+                slots[self.csr.edge_ids[pos]] = Some((self.csr.targets[pos], v, self.csr.weights[pos].clone()));
+            }
+        }
+        let edges: Vec<(usize, usize, W)> = slots.into_iter().map(|e| e.unwrap()).collect();
+        DiGraph::with_weights(self.vertex_count(), &edges)
+    }
+}
+
+macro_rules! impl_adjacency {
+    ($graph:ident, $directed:expr) => {
+        impl<W> Adjacency for $graph<W> {
+            type Weight = W;
+
+            fn vertex_count(&self) -> usize {
+                self.csr.offsets.len() - 1
+            }
+
+            fn edge_count(&self) -> usize {
+                self.csr.edge_count
+            }
+
+            fn is_directed(&self) -> bool {
+                $directed
+            }
+
+            fn neighbors(&self, v: usize) -> &[usize] {
+                &self.csr.targets[self.csr.range(v)]
+            }
+
+            fn weights(&self, v: usize) -> &[W] {
+                &self.csr.weights[self.csr.range(v)]
+            }
+
+            fn edge_ids(&self, v: usize) -> &[usize] {
+                &self.csr.edge_ids[self.csr.range(v)]
+            }
+        }
+    };
+}
+
+impl_adjacency!(Graph, false);
+impl_adjacency!(DiGraph, true);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected() {
+        let graph = Graph::new(4, &[(0, 1), (2, 1), (3, 3), (1, 0)]);
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert!(!graph.is_directed());
+        assert_eq!(graph.neighbors(1), &[0, 2, 0]);
+        assert_eq!(graph.edge_ids(1), &[0, 1, 3]);
+        assert_eq!(graph.neighbors(3), &[3, 3]);
+        assert_eq!(graph.degree(3), 2);
+    }
+
+    #[test]
+    fn directed() {
+        let graph = DiGraph::with_weights(3, &[(0, 1, 'a'), (0, 2, 'b'), (2, 0, 'c')]);
+        assert!(graph.is_directed());
+        assert_eq!(graph.neighbors(0), &[1, 2]);
+        assert_eq!(graph.weights(0), &['a', 'b']);
+        assert!(graph.neighbors(1).is_empty());
+        let reversed = graph.reversed();
+        assert_eq!(reversed.neighbors(0), &[2]);
+        assert_eq!(reversed.weights(2), &['b']);
+        assert_eq!(reversed.edge_ids(1), &[0]);
+    }
+
+    #[test]
+    fn empty() {
+        let graph = Graph::new(0, &[]);
+        assert_eq!(graph.vertex_count(), 0);
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vertex_out_of_range() {
+        DiGraph::new(2, &[(0, 2)]);
+    }
+}
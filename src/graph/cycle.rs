@@ -0,0 +1,177 @@
+use super::Adjacency;
+
+const WHITE: u8 = 0;
+const GRAY: u8 = 1;
+const BLACK: u8 = 2;
+
+/**
+Finds a cycle in a directed or an undirected graph.
+
+Returns vertices of the cycle in the order of edges, the last vertex is connected with the first one.
+In undirected graphs an edge is never used twice in a row, but loops and parallel edges form cycles.
+
+**More:** <https://en.wikipedia.org/wiki/Cycle_(graph_theory)#Algorithm>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, Graph, find_cycle};
+
+fn main(){
+    let graph = DiGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+    assert_eq!(find_cycle(&graph), Some(vec![1, 2, 3]));
+    //the same edges without directions
+    let graph = Graph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(find_cycle(&graph), None);
+}
+```
+*/
+pub fn find_cycle<G>(graph: &G) -> Option<Vec<usize>>
+    where
+        G: Adjacency,
+{
+    let n = graph.vertex_count();
+    let directed = graph.is_directed();
+    let mut color = vec![WHITE; n];
+    //vertex, position of the next edge to check and the edge used to enter the vertex
+    let mut stack: Vec<(usize, usize, Option<usize>)> = Vec::new();
+    //position of gray vertices on the stack
+    let mut depth = vec![0usize; n];
+    for root in 0..n {
+        if color[root] != WHITE {
+            continue;
+        }
+        color[root] = GRAY;
+        stack.push((root, 0, None));
+        while let Some(&(v, pos, entered_by)) = stack.last() {
+            let top = stack.len() - 1;
+            let neighbors = graph.neighbors(v);
+            if pos == neighbors.len() {
+                color[v] = BLACK;
+                stack.pop();
+                continue;
+            }
+            stack[top].1 += 1;
+            let u = neighbors[pos];
+            let edge = graph.edge_ids(v)[pos];
+            if !directed && Some(edge) == entered_by {
+                continue;
+            }
+            match color[u] {
+                WHITE => {
+                    color[u] = GRAY;
+                    depth[u] = stack.len();
+                    stack.push((u, 0, Some(edge)));
+                }
+                //an edge back to a vertex on the stack closes a cycle
+                GRAY => return Some(stack[depth[u]..].iter().map(|s| s.0).collect()),
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/**
+Checks if a directed or an undirected graph contains a cycle, see ```find_cycle()```.
+
+**More:** <https://en.wikipedia.org/wiki/Cycle_(graph_theory)#Algorithm>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+*/
+pub fn has_cycle<G>(graph: &G) -> bool
+    where
+        G: Adjacency,
+{
+    find_cycle(graph).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::{Graph, DiGraph};
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    fn check_cycle<G: Adjacency>(graph: &G, cycle: &[usize]) {
+        assert!(!cycle.is_empty());
+        for (i, &v) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(graph.neighbors(v).contains(&next));
+        }
+        let mut sorted = cycle.to_vec();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), cycle.len());
+    }
+
+    #[test]
+    fn undirected_special_edges() {
+        assert_eq!(find_cycle(&Graph::new(2, &[(1, 1)])), Some(vec![1]));
+        assert_eq!(find_cycle(&Graph::new(2, &[(0, 1), (1, 0)])), Some(vec![0, 1]));
+        assert!(!has_cycle(&Graph::new(2, &[(0, 1)])));
+        assert!(!has_cycle(&Graph::new(0, &[])));
+    }
+
+    #[test]
+    fn directed_special_edges() {
+        assert_eq!(find_cycle(&DiGraph::new(2, &[(0, 0)])), Some(vec![0]));
+        assert_eq!(find_cycle(&DiGraph::new(2, &[(1, 0), (0, 1)])), Some(vec![0, 1]));
+        //two paths to the same vertex
+        assert!(!has_cycle(&DiGraph::new(4, &[(0, 1), (0, 2), (1, 3), (2, 3)])));
+    }
+
+    #[test]
+    fn random_forests() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..50 {
+            let n = rng.gen_range(2, 30);
+            //random forest: every vertex except some roots gets an earlier parent
+            let parent: Vec<Option<usize>> = (0..n).map(|v| {
+                if v > 0 && rng.gen_range(0, 5) > 0 { Some(rng.gen_range(0, v)) } else { None }
+            }).collect();
+            let mut edges: Vec<(usize, usize)> = (0..n).filter_map(|v| parent[v].map(|p| (p, v))).collect();
+            assert!(!has_cycle(&Graph::new(n, &edges)));
+            assert!(!has_cycle(&DiGraph::new(n, &edges)));
+            let (a, b) = (rng.gen_range(0, n), rng.gen_range(0, n));
+            edges.push((a, b));
+            let graph = Graph::new(n, &edges);
+            //in the undirected graph the extra edge creates a cycle if it is a loop or joins vertices of the same tree
+            let root = |mut v: usize| {
+                while let Some(p) = parent[v] {
+                    v = p;
+                }
+                v
+            };
+            let expected = a == b || root(a) == root(b);
+            match find_cycle(&graph) {
+                Some(cycle) => {
+                    assert!(expected);
+                    check_cycle(&graph, &cycle);
+                }
+                None => assert!(!expected),
+            }
+            //in the directed graph the extra edge creates a cycle only if it leads to an ancestor
+            let mut ancestor = Some(a);
+            while ancestor.is_some() && ancestor != Some(b) {
+                ancestor = parent[ancestor.unwrap()];
+            }
+            let expected = ancestor == Some(b);
+            let directed = DiGraph::new(n, &edges);
+            match find_cycle(&directed) {
+                Some(cycle) => {
+                    assert!(expected);
+                    check_cycle(&directed, &cycle);
+                }
+                None => assert!(!expected),
+            }
+        }
+    }
+}
@@ -0,0 +1,716 @@
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+use utils::Integer;
+
+/**
+Flow network with integer capacities for maximum flow and minimum cut algorithms.
+
+Edges are added one by one and keep the current flow, which is increased by ```dinic()``` or ```push_relabel()```.
+Every edge is stored together with its reverse residual edge.
+
+**More:** <https://en.wikipedia.org/wiki/Flow_network>
+
+# Complexity
+
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::FlowNetwork;
+
+fn main(){
+    //assigning 3 workers (vertices 1-3) to 2 tasks (vertices 4-5), the second task needs 2 workers
+    let mut network = FlowNetwork::new(7);
+    let (source, sink) = (0, 6);
+    for worker in 1..4 {
+        network.add_edge(source, worker, 1);
+    }
+    let assignments = [network.add_edge(1, 4, 1), network.add_edge(2, 4, 1), network.add_edge(2, 5, 1), network.add_edge(3, 4, 1)];
+    network.add_edge(4, sink, 1);
+    network.add_edge(5, sink, 2);
+    assert_eq!(network.dinic(source, sink), 2);
+    let used: Vec<u32> = assignments.iter().map(|&e| network.flow(e)).collect();
+    assert_eq!(used.iter().sum::<u32>(), 2);
+    assert_eq!(network.flow(assignments[2]), 1);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct FlowNetwork<C> {
+    //edge 2i is the i-th added edge and 2i+1 is its reverse residual edge
+    to: Vec<usize>,
+    residual: Vec<C>,
+    //capacity above the lower bound and the lower bound of every added edge
+    capacity: Vec<C>,
+    lower: Vec<C>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<C> FlowNetwork<C>
+    where
+        C: Integer + Add<Output = C> + Sub<Output = C>,
+{
+    /**
+    Creates a network with the given number of vertices and no edges.
+
+    **Complexity:** O(V)
+    */
+    pub fn new(vertices: usize) -> Self {
+        FlowNetwork {
+            to: Vec::new(),
+            residual: Vec::new(),
+            capacity: Vec::new(),
+            lower: Vec::new(),
+            adjacency: vec![Vec::new(); vertices],
+        }
+    }
+
+    ///Number of vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    ///Number of added edges.
+    pub fn edge_count(&self) -> usize {
+        self.capacity.len()
+    }
+
+    /**
+    Adds an edge with the given capacity and returns its index.
+
+    Panics if the edge goes beyond the number of vertices or the capacity is negative.
+
+    **Complexity:** O(1)
+    */
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: C) -> usize {
+        self.add_edge_with_lower_bound(from, to, C::zero(), capacity)
+    }
+
+    /**
+    Adds an edge whose flow has to be between the lower and the upper bound and returns its index.
+
+    Networks with lower bounds are solved by ```max_flow_with_lower_bounds()```.
+    Panics if the edge goes beyond the number of vertices or the bounds are not ```0 <= lower <= upper```.
+
+    **Complexity:** O(1)
+    */
+    pub fn add_edge_with_lower_bound(&mut self, from: usize, to: usize, lower: C, upper: C) -> usize {
+        let n = self.vertex_count();
+        assert!(from < n && to < n, "Edge ({}, {}) goes beyond {} vertices", from, to, n);
+        assert!(C::zero() <= lower && lower <= upper, "Invalid bounds of the edge ({}, {})", from, to);
+        let id = self.capacity.len();
+        self.to.push(to);
+        self.residual.push(upper - lower);
+        self.adjacency[from].push(2 * id);
+        self.to.push(from);
+        self.residual.push(C::zero());
+        self.adjacency[to].push(2 * id + 1);
+        self.capacity.push(upper - lower);
+        self.lower.push(lower);
+        id
+    }
+
+    /**
+    Returns the current flow through the edge.
+
+    **Complexity:** O(1)
+    */
+    pub fn flow(&self, edge: usize) -> C {
+        self.lower[edge] + self.residual[2 * edge + 1]
+    }
+
+    /**
+    Removes the flow from all edges.
+
+    **Complexity:** O(E)
+    */
+    pub fn reset(&mut self) {
+        for (id, &capacity) in self.capacity.iter().enumerate() {
+            self.residual[2 * id] = capacity;
+            self.residual[2 * id + 1] = C::zero();
+        }
+    }
+
+    fn assert_no_lower_bounds(&self) {
+        assert!(self.lower.iter().all(|&l| l == C::zero()), "Use max_flow_with_lower_bounds() for networks with lower bounds");
+    }
+
+    //distances from the source in the residual network, None for unreachable vertices
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let mut level = vec![None; self.vertex_count()];
+        level[source] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+        while let Some(v) = queue.pop_front() {
+            for &e in &self.adjacency[v] {
+                let u = self.to[e];
+                if self.residual[e] > C::zero() && level[u].is_none() {
+                    level[u] = level[v].map(|l| l + 1);
+                    queue.push_back(u);
+                }
+            }
+        }
+        level
+    }
+
+    fn push(&mut self, e: usize, amount: C) {
+        self.residual[e] = self.residual[e] - amount;
+        self.residual[e ^ 1] = self.residual[e ^ 1] + amount;
+    }
+
+    fn dinic_residual(&mut self, source: usize, sink: usize) -> C {
+        let n = self.vertex_count();
+        let mut total = C::zero();
+        if source == sink {
+            return total;
+        }
+        loop {
+            let mut level = self.levels(source);
+            if level[sink].is_none() {
+                return total;
+            }
+            //position of the next edge to check in every adjacency list
+            let mut next = vec![0usize; n];
+            //the current path of edges from the source, built by an iterative depth-first search
+            let mut path: Vec<usize> = Vec::new();
+            let mut v = source;
+            loop {
+                if v == sink {
+                    let amount = path.iter().map(|&e| self.residual[e]).min().unwrap();
+                    for &e in &path {
+                        self.push(e, amount);
+                    }
+                    total = total + amount;
+                    //continue from the tail of the first saturated edge
+                    let saturated = path.iter().position(|&e| self.residual[e] == C::zero()).unwrap();
+                    path.truncate(saturated);
+                    v = path.last().map_or(source, |&e| self.to[e]);
+                    continue;
+                }
+                let mut advanced = false;
+                while next[v] < self.adjacency[v].len() {
+                    let e = self.adjacency[v][next[v]];
+                    let u = self.to[e];
+                    if self.residual[e] > C::zero() && level[u].is_some() && level[u] == level[v].map(|l| l + 1) {
+                        path.push(e);
+                        v = u;
+                        advanced = true;
+                        break;
+                    }
+                    next[v] += 1;
+                }
+                if advanced {
+                    continue;
+                }
+                //a dead end is removed from the level graph
+                if v == source {
+                    break;
+                }
+                level[v] = None;
+                let e = path.pop().unwrap();
+                v = self.to[e ^ 1];
+                next[v] += 1;
+            }
+        }
+    }
+
+    /**
+    Increases the current flow to a maximum flow from the source to the sink using Dinic's algorithm and returns the increase.
+
+    For a network without flow the result is the value of the maximum flow.
+    Every phase finds a blocking flow in the level graph of shortest residual paths using an iterative depth-first search.
+    The increase of the flow value needs to fit in ```C```.
+    Panics if any edge has a lower bound.
+
+    **More:** <https://en.wikipedia.org/wiki/Dinic%27s_algorithm>
+
+    # Complexity
+
+    - Processing complexity: O(V^2 * E), O(E * sqrt(V)) for unit capacity networks like bipartite matching
+    - Memory complexity: O(V)
+
+    # Example
+    ```
+    extern crate algorithm;
+    use algorithm::graph::FlowNetwork;
+
+    fn main(){
+        let mut network = FlowNetwork::new(4);
+        network.add_edge(0, 1, 4u64);
+        network.add_edge(0, 2, 2);
+        network.add_edge(1, 2, 5);
+        network.add_edge(1, 3, 2);
+        network.add_edge(2, 3, 3);
+        assert_eq!(network.dinic(0, 3), 5);
+        assert_eq!(network.min_cut(0), vec![0, 1, 2]);
+    }
+    ```
+    */
+    pub fn dinic(&mut self, source: usize, sink: usize) -> C {
+        self.assert_no_lower_bounds();
+        self.dinic_residual(source, sink)
+    }
+
+    /**
+    Increases the current flow to a maximum flow from the source to the sink using the highest-label
+    push-relabel algorithm and returns the increase.
+
+    Vertices with excess flow are processed in the order of decreasing labels (heights),
+    and the gap heuristic lifts vertices that cannot reach the sink anymore.
+    Excess flow of a vertex can temporarily exceed the range of ```C``` and is counted without overflow,
+    only the increase of the flow value needs to fit in ```C```.
+    Panics if any edge has a lower bound or if the increase does not fit in ```C```.
+
+    **More:** <https://en.wikipedia.org/wiki/Push%E2%80%93relabel_maximum_flow_algorithm>
+
+    # Complexity
+
+    - Processing complexity: O(V^2 * sqrt(E))
+    - Memory complexity: O(V)
+
+    # Example
+    ```
+    extern crate algorithm;
+    use algorithm::graph::FlowNetwork;
+
+    fn main(){
+        let mut network = FlowNetwork::new(4);
+        network.add_edge(0, 1, 4i32);
+        network.add_edge(0, 2, 2);
+        network.add_edge(1, 2, 5);
+        network.add_edge(1, 3, 2);
+        network.add_edge(2, 3, 3);
+        assert_eq!(network.push_relabel(0, 3), 5);
+    }
+    ```
+    */
+    pub fn push_relabel(&mut self, source: usize, sink: usize) -> C {
+        self.assert_no_lower_bounds();
+        let n = self.vertex_count();
+        if source == sink {
+            return C::zero();
+        }
+        let mut height = vec![0usize; n];
+        let mut excess = vec![Excess::zero(); n];
+        //number of vertices with every height, heights never exceed 2V
+        let mut count = vec![0usize; 2 * n + 1];
+        //active vertices (other than the source and the sink) with excess flow, grouped by heights
+        let mut active: Vec<Vec<usize>> = vec![Vec::new(); 2 * n + 1];
+        let mut highest = 0;
+        let mut next = vec![0usize; n];
+        height[source] = n;
+        count[0] = n - 1;
+        count[n] = 1;
+        for i in 0..self.adjacency[source].len() {
+            let e = self.adjacency[source][i];
+            let amount = self.residual[e];
+            if amount > C::zero() {
+                let u = self.to[e];
+                self.push(e, amount);
+                if excess[u].is_zero() && u != source && u != sink {
+                    active[0].push(u);
+                }
+                excess[u].add(amount);
+            }
+        }
+        loop {
+            while highest > 0 && active[highest].is_empty() {
+                highest -= 1;
+            }
+            let v = match active[highest].pop() {
+                Some(v) => v,
+                None => break,
+            };
+            //discharge the vertex
+            while !excess[v].is_zero() {
+                if next[v] == self.adjacency[v].len() {
+                    let old = height[v];
+                    let new = self.adjacency[v].iter().filter(|&&e| self.residual[e] > C::zero())
+                        .map(|&e| height[self.to[e]] + 1).min().unwrap();
+                    count[old] -= 1;
+                    height[v] = new;
+                    count[new] += 1;
+                    next[v] = 0;
+                    //gap: vertices above the empty height cannot reach the sink,
+                    //active ones are lifted too but stay in their buckets
+                    if count[old] == 0 && old < n {
+                        for u in 0..n {
+                            if height[u] > old && height[u] < n {
+                                count[height[u]] -= 1;
+                                height[u] = n + 1;
+                                count[n + 1] += 1;
+                            }
+                        }
+                    }
+                    continue;
+                }
+                let e = self.adjacency[v][next[v]];
+                let u = self.to[e];
+                if self.residual[e] > C::zero() && height[v] == height[u] + 1 {
+                    let amount = excess[v].at_most(self.residual[e]);
+                    self.push(e, amount);
+                    excess[v].sub(amount);
+                    if excess[u].is_zero() && u != source && u != sink {
+                        active[height[u]].push(u);
+                        highest = highest.max(height[u]);
+                    }
+                    excess[u].add(amount);
+                } else {
+                    next[v] += 1;
+                }
+            }
+        }
+        assert!(excess[sink].overflows == 0, "The flow value does not fit in the capacity type");
+        excess[sink].low
+    }
+
+    /**
+    Returns the source side of the minimum cut: vertices reachable from the source in the residual network.
+
+    The flow has to be maximum, edges leaving the returned set are saturated and their total capacity is equal to the flow value.
+    Vertices are returned in the increasing order.
+
+    **More:** <https://en.wikipedia.org/wiki/Max-flow_min-cut_theorem>
+
+    # Complexity
+
+    - Processing complexity: O(V + E)
+    - Memory complexity: O(V)
+    */
+    pub fn min_cut(&self, source: usize) -> Vec<usize> {
+        let level = self.levels(source);
+        (0..self.vertex_count()).filter(|&v| level[v].is_some()).collect()
+    }
+
+    /**
+    Finds a maximum flow from the source to the sink that satisfies lower bounds of all edges.
+
+    Returns None if no flow with a non-negative value satisfies the bounds. The current flow is discarded, flows of edges are available through ```flow()```.
+    A feasible flow is found as a maximum flow between two additional vertices that supply and consume the lower bounds,
+    then it is increased by Dinic's algorithm.
+
+    **More:** <https://en.wikipedia.org/wiki/Circulation_problem>
+
+    # Complexity
+
+    - Processing complexity: O(V^2 * E)
+    - Memory complexity: O(V + E)
+
+    # Example
+    ```
+    extern crate algorithm;
+    use algorithm::graph::FlowNetwork;
+
+    fn main(){
+        let mut network = FlowNetwork::new(3);
+        let first = network.add_edge_with_lower_bound(0, 1, 2, 5u32);
+        let second = network.add_edge_with_lower_bound(1, 2, 1, 3);
+        assert_eq!(network.max_flow_with_lower_bounds(0, 2), Some(3));
+        assert_eq!((network.flow(first), network.flow(second)), (3, 3));
+
+        //the second edge cannot take 2 units of flow
+        let mut network = FlowNetwork::new(3);
+        network.add_edge_with_lower_bound(0, 1, 2, 5u32);
+        network.add_edge(1, 2, 1);
+        assert_eq!(network.max_flow_with_lower_bounds(0, 2), None);
+    }
+    ```
+    */
+    pub fn max_flow_with_lower_bounds(&mut self, source: usize, sink: usize) -> Option<C> {
+        self.reset();
+        let n = self.vertex_count();
+        let edges = self.edge_count();
+        let mut incoming = vec![C::zero(); n];
+        let mut outgoing = vec![C::zero(); n];
+        for id in 0..edges {
+            let to = self.to[2 * id];
+            let from = self.to[2 * id + 1];
+            incoming[to] = incoming[to] + self.lower[id];
+            outgoing[from] = outgoing[from] + self.lower[id];
+        }
+        //lower bounds are supplied by the additional source and consumed by the additional sink
+        let (supply, demand) = (n, n + 1);
+        self.adjacency.push(Vec::new());
+        self.adjacency.push(Vec::new());
+        let back = self.add_edge(sink, source, C::max_value());
+        let mut required = C::zero();
+        for v in 0..n {
+            if incoming[v] > outgoing[v] {
+                self.add_edge(supply, v, incoming[v] - outgoing[v]);
+                required = required + (incoming[v] - outgoing[v]);
+            } else if outgoing[v] > incoming[v] {
+                self.add_edge(v, demand, outgoing[v] - incoming[v]);
+            }
+        }
+        let feasible = self.dinic_residual(supply, demand) == required;
+        let value = self.residual[2 * back + 1];
+        //remove the additional vertices and edges
+        self.to.truncate(2 * edges);
+        self.residual.truncate(2 * edges);
+        self.capacity.truncate(edges);
+        self.lower.truncate(edges);
+        self.adjacency.truncate(n);
+        for list in &mut self.adjacency {
+            list.retain(|&e| e < 2 * edges);
+        }
+        if !feasible {
+            self.reset();
+            return None;
+        }
+        Some(value + self.dinic_residual(source, sink))
+    }
+}
+
+//excess flow of a vertex in the push-relabel algorithm, it can exceed the range of C:
+//the value is overflows * (max + 1) + low
+#[derive(Clone, Copy)]
+struct Excess<C> {
+    overflows: usize,
+    low: C,
+}
+
+impl<C> Excess<C>
+    where
+        C: Integer + Add<Output = C> + Sub<Output = C>,
+{
+    fn zero() -> Self {
+        Excess { overflows: 0, low: C::zero() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.overflows == 0 && self.low == C::zero()
+    }
+
+    //the excess, but not more than the limit
+    fn at_most(&self, limit: C) -> C {
+        if self.overflows > 0 || self.low > limit { limit } else { self.low }
+    }
+
+    fn add(&mut self, amount: C) {
+        match self.low.checked_add(amount) {
+            Some(sum) => self.low = sum,
+            None => {
+                //low + amount - (max + 1), arranged so that no step overflows
+                self.overflows += 1;
+                self.low = amount - (C::max_value() - self.low) - C::one();
+            }
+        }
+    }
+
+    //requires amount not greater than the excess
+    fn sub(&mut self, amount: C) {
+        if self.low >= amount {
+            self.low = self.low - amount;
+        } else {
+            self.overflows -= 1;
+            self.low = C::max_value() - amount + self.low + C::one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    fn random_edges(rng: &mut XorShiftRng, n: usize, m: usize, max: u32) -> Vec<(usize, usize, u32)> {
+        (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n), rng.gen_range(0, max))).collect()
+    }
+
+    fn network(n: usize, edges: &[(usize, usize, u32)]) -> FlowNetwork<u32> {
+        let mut network = FlowNetwork::new(n);
+        for &(a, b, c) in edges {
+            network.add_edge(a, b, c);
+        }
+        network
+    }
+
+    //flow conservation and capacities, returns the value of the flow
+    fn check_flow(network: &FlowNetwork<u32>, edges: &[(usize, usize, u32)], source: usize, sink: usize) -> i64 {
+        let mut balance = vec![0i64; network.vertex_count()];
+        for (id, &(a, b, c)) in edges.iter().enumerate() {
+            let flow = network.flow(id);
+            assert!(flow <= c);
+            balance[a] -= flow as i64;
+            balance[b] += flow as i64;
+        }
+        for (v, &b) in balance.iter().enumerate() {
+            if v != source && v != sink {
+                assert_eq!(b, 0);
+            }
+        }
+        balance[sink]
+    }
+
+    //the lightest cut among all sets of vertices containing the source but not the sink
+    fn brute_force_cut(n: usize, edges: &[(usize, usize, u32)], source: usize, sink: usize) -> u32 {
+        (0u32..1 << n).filter(|mask| mask & (1 << source) != 0 && mask & (1 << sink) == 0).map(|mask| {
+            edges.iter().filter(|e| mask & (1 << e.0) != 0 && mask & (1 << e.1) == 0).map(|e| e.2).sum()
+        }).min().unwrap()
+    }
+
+    #[test]
+    fn algorithms_agree() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..200 {
+            let n = rng.gen_range(2, 9);
+            let m = rng.gen_range(0, 25);
+            let edges = random_edges(&mut rng, n, m, 10);
+            let (source, sink) = (0, n - 1);
+            let expected = brute_force_cut(n, &edges, source, sink);
+            let mut first = network(n, &edges);
+            assert_eq!(first.dinic(source, sink), expected);
+            assert_eq!(check_flow(&first, &edges, source, sink), expected as i64);
+            let mut second = network(n, &edges);
+            assert_eq!(second.push_relabel(source, sink), expected);
+            assert_eq!(check_flow(&second, &edges, source, sink), expected as i64);
+            for net in &[first, second] {
+                let side = net.min_cut(source);
+                assert!(side.contains(&source) && !side.contains(&sink));
+                let cut: u32 = edges.iter().filter(|e| side.contains(&e.0) && !side.contains(&e.1)).map(|e| e.2).sum();
+                assert_eq!(cut, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn large_networks_agree() {
+        let mut rng = XorShiftRng::from_seed([1, 1, 2, 2]);
+        for _ in 0..50 {
+            let n = rng.gen_range(2, 80);
+            let m = rng.gen_range(0, 400);
+            let edges = random_edges(&mut rng, n, m, 100);
+            let mut first = network(n, &edges);
+            let mut second = network(n, &edges);
+            let value = first.dinic(0, n - 1);
+            assert_eq!(second.push_relabel(0, n - 1), value);
+            assert_eq!(check_flow(&second, &edges, 0, n - 1), value as i64);
+        }
+    }
+
+    #[test]
+    fn augmenting_and_reset() {
+        let mut network = FlowNetwork::new(3);
+        network.add_edge(0, 1, 4u8);
+        let second = network.add_edge(1, 2, 2);
+        assert_eq!(network.dinic(0, 2), 2);
+        assert_eq!(network.dinic(0, 2), 0);
+        network.reset();
+        assert_eq!(network.flow(second), 0);
+        assert_eq!(network.push_relabel(0, 2), 2);
+        assert_eq!(network.dinic(0, 0), 0);
+    }
+
+    #[test]
+    fn long_path() {
+        let n = 100_000;
+        let mut network = FlowNetwork::new(n);
+        for v in 1..n {
+            network.add_edge(v - 1, v, 1 + (v % 7) as i64);
+        }
+        assert_eq!(network.clone().dinic(0, n - 1), 1);
+        assert_eq!(network.push_relabel(0, n - 1), 1);
+    }
+
+    #[test]
+    fn narrow_capacities() {
+        //sum of capacities leaving the source does not fit in u8
+        let mut network = FlowNetwork::new(3);
+        network.add_edge(0, 1, 200u8);
+        network.add_edge(0, 1, 200);
+        network.add_edge(1, 2, 2);
+        assert_eq!(network.clone().dinic(0, 2), 2);
+        assert_eq!(network.clone().push_relabel(0, 2), 2);
+        network.push_relabel(0, 2);
+        assert_eq!(network.min_cut(0), vec![0, 1]);
+        let mut network = FlowNetwork::new(2);
+        network.add_edge(0, 1, 200u8);
+        network.add_edge(0, 1, 50);
+        assert_eq!(network.push_relabel(0, 1), 250);
+        //the excess of the vertex 1 temporarily exceeds 255
+        let edges = [(0, 1, 244u8), (1, 2, 62), (2, 1, 71), (1, 3, 177), (0, 2, 66)];
+        let mut network = FlowNetwork::new(4);
+        for &(a, b, c) in &edges {
+            network.add_edge(a, b, c);
+        }
+        assert_eq!(network.clone().dinic(0, 3), 177);
+        assert_eq!(network.push_relabel(0, 3), 177);
+        let mut network = FlowNetwork::new(4);
+        for &(a, b, c) in &edges {
+            network.add_edge(a, b, (c / 2) as i8);
+        }
+        assert_eq!(network.clone().dinic(0, 3), 88);
+        assert_eq!(network.push_relabel(0, 3), 88);
+    }
+
+    //the greatest non-negative flow value among all assignments of flows within bounds, None if no assignment is valid
+    fn brute_force_bounds(n: usize, edges: &[(usize, usize, u32, u32)], source: usize, sink: usize) -> Option<i64> {
+        let mut flows: Vec<u32> = edges.iter().map(|e| e.2).collect();
+        let mut best = None;
+        loop {
+            let mut balance = vec![0i64; n];
+            for (e, &f) in edges.iter().zip(&flows) {
+                balance[e.0] -= f as i64;
+                balance[e.1] += f as i64;
+            }
+            let valid = (0..n).all(|v| v == source || v == sink || balance[v] == 0) && balance[sink] >= 0;
+            if valid && best.is_none_or(|b| balance[sink] > b) {
+                best = Some(balance[sink]);
+            }
+            //next assignment of flows
+            let mut i = 0;
+            while i < edges.len() && flows[i] == edges[i].3 {
+                flows[i] = edges[i].2;
+                i += 1;
+            }
+            if i == edges.len() {
+                return best;
+            }
+            flows[i] += 1;
+        }
+    }
+
+    #[test]
+    fn lower_bounds_brute_force() {
+        let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+        let mut infeasible = 0;
+        for _ in 0..300 {
+            let n = rng.gen_range(2, 5);
+            let m = rng.gen_range(1, 5);
+            let edges: Vec<(usize, usize, u32, u32)> = (0..m).map(|_| {
+                let lower = rng.gen_range(0, 3);
+                let (a, b) = (rng.gen_range(0, n), rng.gen_range(0, n));
+                (a, b, lower, lower + rng.gen_range(0, 3))
+            }).filter(|e| e.0 != e.1).collect();
+            let (source, sink) = (0, n - 1);
+            let mut network = FlowNetwork::new(n);
+            for &(a, b, lower, upper) in &edges {
+                network.add_edge_with_lower_bound(a, b, lower, upper);
+            }
+            let expected = brute_force_bounds(n, &edges, source, sink);
+            let found = network.max_flow_with_lower_bounds(source, sink);
+            assert_eq!(found.map(|f| f as i64), expected);
+            assert_eq!(network.edge_count(), edges.len());
+            match found {
+                Some(value) => {
+                    let bounded: Vec<(usize, usize, u32)> = edges.iter().map(|e| (e.0, e.1, e.3)).collect();
+                    assert_eq!(check_flow(&network, &bounded, source, sink), value as i64);
+                    for (id, e) in edges.iter().enumerate() {
+                        assert!(network.flow(id) >= e.2);
+                    }
+                }
+                None => infeasible += 1,
+            }
+        }
+        assert!(infeasible > 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dinic_with_lower_bounds() {
+        let mut network = FlowNetwork::new(2);
+        network.add_edge_with_lower_bound(0, 1, 1, 2i32);
+        network.dinic(0, 1);
+    }
+}
@@ -0,0 +1,28 @@
+/*!
+Graph algorithms.
+
+Graphs are stored in the compressed sparse row format, vertices are numbered from 0.
+Algorithms are generic over the ```Adjacency``` trait where edge directions do not matter.
+*/
+
+mod csr;
+mod traversal;
+mod connectivity;
+mod cycle;
+mod shortest_paths;
+mod spanning_tree;
+mod scc;
+mod topological;
+mod biconnected;
+mod flow;
+
+pub use self::csr::{Adjacency, Graph, DiGraph};
+pub use self::traversal::{bfs, dfs, dfs_forest, BfsTree, Dfs, DfsEvent};
+pub use self::connectivity::{connected_components, bipartition, is_bipartite};
+pub use self::cycle::{find_cycle, has_cycle};
+pub use self::shortest_paths::{dijkstra, zero_one_bfs, bellman_ford, spfa, floyd_warshall, a_star, ShortestPaths, AllPairsShortestPaths};
+pub use self::spanning_tree::{kruskal, prim, boruvka, minimum_spanning_forest};
+pub use self::scc::{tarjan_scc, kosaraju_scc, condensation};
+pub use self::topological::{topological_sort, dag_longest_path};
+pub use self::biconnected::{bridges, articulation_points, two_edge_connected_components, biconnected_components, block_cut_tree, BlockCutTree};
+pub use self::flow::FlowNetwork;
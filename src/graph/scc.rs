@@ -0,0 +1,240 @@
+use super::{Adjacency, DiGraph, DfsEvent};
+use super::traversal::dfs_from;
+
+/**
+Finds strongly connected components of a directed graph using Tarjan's algorithm.
+
+Returns the number of components and the component index of every vertex.
+Components are numbered in a topological order: every edge leads to a component with an equal or greater index.
+
+**More:** <https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, tarjan_scc};
+
+fn main(){
+    let graph = DiGraph::new(5, &[(0, 1), (1, 2), (2, 0), (3, 2), (3, 4), (4, 3)]);
+    assert_eq!(tarjan_scc(&graph), (2, vec![1, 1, 1, 0, 0]));
+}
+```
+*/
+pub fn tarjan_scc<W>(graph: &DiGraph<W>) -> (usize, Vec<usize>) {
+    let n = graph.vertex_count();
+    let mut index: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0usize; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    //explicit call stack: vertex and the position of the next edge to check
+    let mut calls: Vec<(usize, usize)> = Vec::new();
+    let mut component = vec![0usize; n];
+    let mut count = 0;
+    let mut counter = 0;
+    for root in 0..n {
+        if index[root].is_some() {
+            continue;
+        }
+        index[root] = Some(counter);
+        low[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        calls.push((root, 0));
+        while let Some(&(v, pos)) = calls.last() {
+            let neighbors = graph.neighbors(v);
+            if pos < neighbors.len() {
+                let top = calls.len() - 1;
+                calls[top].1 += 1;
+                let u = neighbors[pos];
+                match index[u] {
+                    None => {
+                        index[u] = Some(counter);
+                        low[u] = counter;
+                        counter += 1;
+                        stack.push(u);
+                        on_stack[u] = true;
+                        calls.push((u, 0));
+                    }
+                    Some(iu) => if on_stack[u] {
+                        low[v] = low[v].min(iu);
+                    }
+                }
+                continue;
+            }
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent] = low[parent].min(low[v]);
+            }
+            if Some(low[v]) == index[v] {
+                //v is the root of a component, which consists of vertices above it on the stack
+                loop {
+                    let u = stack.pop().unwrap();
+                    on_stack[u] = false;
+                    component[u] = count;
+                    if u == v {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+    //Tarjan's algorithm finds components in the reversed topological order
+    for c in &mut component {
+        *c = count - 1 - *c;
+    }
+    (count, component)
+}
+
+/**
+Finds strongly connected components of a directed graph using Kosaraju's algorithm.
+
+The first depth-first search orders vertices by their finishing times, the second one
+visits the reversed graph in the opposite order and every search tree is a component.
+Components are numbered in a topological order, like in ```tarjan_scc()```.
+
+**More:** <https://en.wikipedia.org/wiki/Kosaraju%27s_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, kosaraju_scc};
+
+fn main(){
+    let graph = DiGraph::new(5, &[(0, 1), (1, 2), (2, 0), (3, 2), (3, 4), (4, 3)]);
+    assert_eq!(kosaraju_scc(&graph), (2, vec![1, 1, 1, 0, 0]));
+}
+```
+*/
+pub fn kosaraju_scc<W>(graph: &DiGraph<W>) -> (usize, Vec<usize>)
+    where
+        W: Clone,
+{
+    let n = graph.vertex_count();
+    let mut roots: Vec<usize> = dfs_from(graph, (0..n).collect()).filter_map(|event| match event {
+        DfsEvent::Finish(v) => Some(v),
+        _ => None,
+    }).collect();
+    roots.reverse();
+    let reversed = graph.reversed();
+    let mut component = vec![0usize; n];
+    let mut count = 0;
+    for event in dfs_from(&reversed, roots) {
+        if let DfsEvent::Discover(v, parent) = event {
+            if parent.is_none() {
+                count += 1;
+            }
+            component[v] = count - 1;
+        }
+    }
+    (count, component)
+}
+
+/**
+Creates the condensation of a directed graph: every strongly connected component becomes a single vertex.
+
+Returns the acyclic graph of components (without loops and parallel edges) and the component index of every vertex,
+components are numbered like in ```tarjan_scc()```.
+
+**More:** <https://en.wikipedia.org/wiki/Strongly_connected_component>
+
+# Complexity
+
+- Processing complexity: O(V + E * log(E))
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, Adjacency, condensation};
+
+fn main(){
+    let graph = DiGraph::new(5, &[(0, 1), (1, 0), (1, 2), (0, 2), (2, 3), (3, 2), (4, 3)]);
+    let (dag, component) = condensation(&graph);
+    assert_eq!(component, vec![1, 1, 2, 2, 0]);
+    assert_eq!(dag.neighbors(0), &[2]);
+    assert_eq!(dag.neighbors(1), &[2]);
+    assert_eq!(dag.edge_count(), 2);
+}
+```
+*/
+pub fn condensation<W>(graph: &DiGraph<W>) -> (DiGraph, Vec<usize>) {
+    let (count, component) = tarjan_scc(graph);
+    let mut edges = Vec::new();
+    for v in 0..graph.vertex_count() {
+        for &u in graph.neighbors(v) {
+            if component[v] != component[u] {
+                edges.push((component[v], component[u]));
+            }
+        }
+    }
+    edges.sort();
+    edges.dedup();
+    (DiGraph::new(count, &edges), component)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::{bfs, has_cycle};
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    fn check_components(graph: &DiGraph, (count, component): (usize, Vec<usize>)) {
+        let n = graph.vertex_count();
+        let reachable: Vec<Vec<Option<usize>>> = (0..n).map(|v| bfs(graph, v).distance).collect();
+        for a in 0..n {
+            for b in 0..n {
+                let strongly = reachable[a][b].is_some() && reachable[b][a].is_some();
+                assert_eq!(component[a] == component[b], strongly);
+            }
+            for &b in graph.neighbors(a) {
+                assert!(component[a] <= component[b]);
+            }
+        }
+        assert_eq!(count, component.iter().map(|&c| c + 1).max().unwrap_or(0));
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..100 {
+            let n = rng.gen_range(0, 30);
+            let m = if n == 0 { 0 } else { rng.gen_range(0, 2 * n) };
+            let edges: Vec<(usize, usize)> = (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n))).collect();
+            let graph = DiGraph::new(n, &edges);
+            check_components(&graph, tarjan_scc(&graph));
+            check_components(&graph, kosaraju_scc(&graph));
+            let (dag, component) = condensation(&graph);
+            assert!(!has_cycle(&dag));
+            for &(a, b) in &edges {
+                if component[a] != component[b] {
+                    assert!(dag.neighbors(component[a]).contains(&component[b]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deep() {
+        let n = 200_000;
+        let mut edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+        edges.push((n - 1, 1));
+        let graph = DiGraph::new(n, &edges);
+        let (count, component) = tarjan_scc(&graph);
+        assert_eq!(count, 2);
+        assert_eq!(component[0], 0);
+        assert!(component[1..].iter().all(|&c| c == 1));
+        assert_eq!(kosaraju_scc(&graph), (count, component));
+    }
+}
@@ -0,0 +1,618 @@
+use super::Adjacency;
+use super::traversal::path_from_predecessors;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Add;
+
+/**
+Shortest paths from a single source, created by ```dijkstra()```, ```bellman_ford()``` and other functions of this module.
+*/
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<W> {
+    ///Length of the shortest path from the source, None for unreachable vertices.
+    pub distance: Vec<Option<W>>,
+    ///Previous vertex on the shortest path from the source, None for the source and unreachable vertices.
+    pub predecessor: Vec<Option<usize>>,
+}
+
+impl<W> ShortestPaths<W> {
+    fn new(vertices: usize, source: usize) -> Self
+        where
+            W: Default,
+    {
+        let mut distance: Vec<Option<W>> = (0..vertices).map(|_| None).collect();
+        distance[source] = Some(W::default());
+        ShortestPaths {
+            distance,
+            predecessor: vec![None; vertices],
+        }
+    }
+
+    /**
+    Returns vertices on the shortest path from the source to the target, None if the target is unreachable.
+
+    **Complexity:** O(length of the path)
+    */
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance[target].as_ref().map(|_| path_from_predecessors(&self.predecessor, target))
+    }
+}
+
+/**
+Shortest paths between all pairs of vertices, created by ```floyd_warshall()```.
+*/
+#[derive(Clone, Debug)]
+pub struct AllPairsShortestPaths<W> {
+    ///```distance[from][to]``` is the length of the shortest path, None if ```to``` is unreachable from ```from```.
+    pub distance: Vec<Vec<Option<W>>>,
+    ///```predecessor[from][to]``` is the previous vertex on the shortest path, None if ```from == to``` or the path does not exist.
+    pub predecessor: Vec<Vec<Option<usize>>>,
+}
+
+impl<W> AllPairsShortestPaths<W> {
+    /**
+    Returns vertices on the shortest path between two vertices, None if the path does not exist.
+
+    **Complexity:** O(length of the path)
+    */
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.distance[from][to].as_ref().map(|_| path_from_predecessors(&self.predecessor[from], to))
+    }
+}
+
+//any cycle in the graph of predecessors created by relaxations has a negative length
+fn predecessor_cycle(predecessor: &[Option<usize>], start: usize) -> Option<Vec<usize>> {
+    let mut on_path = vec![false; predecessor.len()];
+    let mut v = start;
+    while !on_path[v] {
+        on_path[v] = true;
+        v = predecessor[v]?;
+    }
+    let mut cycle = vec![v];
+    let mut u = predecessor[v].unwrap();
+    while u != v {
+        cycle.push(u);
+        u = predecessor[u].unwrap();
+    }
+    cycle.reverse();
+    Some(cycle)
+}
+
+/**
+Finds shortest paths from the source in a graph with non-negative weights using Dijkstra's algorithm.
+
+Vertices are processed in the order of increasing distance using a binary heap.
+Panics if the search reaches an edge with a negative weight.
+
+**More:** <https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm>
+
+# Complexity
+
+- Processing complexity: O((V + E) * log(V))
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, dijkstra};
+
+fn main(){
+    let graph = DiGraph::with_weights(4, &[(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5)]);
+    let paths = dijkstra(&graph, 0);
+    assert_eq!(paths.distance, vec![Some(0), Some(3), Some(1), Some(8)]);
+    assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+}
+```
+*/
+pub fn dijkstra<G, W>(graph: &G, source: usize) -> ShortestPaths<W>
+    where
+        G: Adjacency<Weight = W>,
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut paths = ShortestPaths::new(graph.vertex_count(), source);
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((W::default(), source)));
+    while let Some(Reverse((d, v))) = heap.pop() {
+        //skip entries that have been improved after they were pushed
+        if paths.distance[v] != Some(d) {
+            continue;
+        }
+        for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+            assert!(w >= W::default(), "Dijkstra's algorithm does not support negative weights");
+            let candidate = d + w;
+            if paths.distance[u].is_none_or(|du| candidate < du) {
+                paths.distance[u] = Some(candidate);
+                paths.predecessor[u] = Some(v);
+                heap.push(Reverse((candidate, u)));
+            }
+        }
+    }
+    paths
+}
+
+/**
+Finds shortest paths from the source in a graph with two possible weights: zero (```Default```) and one positive value.
+
+A double-ended queue replaces the priority queue of Dijkstra's algorithm: vertices reached through edges
+of zero weight are added to the front and other vertices to the back of the queue.
+Panics if the search reaches edges with two different non-zero weights or a negative weight.
+
+**More:** <https://en.wikipedia.org/wiki/Breadth-first_search#0-1_BFS>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, zero_one_bfs};
+
+fn main(){
+    //changing the line costs 1, traveling along the line is free
+    let graph = Graph::with_weights(5, &[(0, 1, 0), (1, 2, 0), (0, 3, 1), (3, 2, 1), (2, 4, 1)]);
+    let paths = zero_one_bfs(&graph, 0);
+    assert_eq!(paths.distance, vec![Some(0), Some(0), Some(0), Some(1), Some(1)]);
+}
+```
+*/
+pub fn zero_one_bfs<G, W>(graph: &G, source: usize) -> ShortestPaths<W>
+    where
+        G: Adjacency<Weight = W>,
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut paths = ShortestPaths::new(n, source);
+    let mut done = vec![false; n];
+    let mut unit: Option<W> = None;
+    let mut deque = VecDeque::new();
+    deque.push_back(source);
+    while let Some(v) = deque.pop_front() {
+        if done[v] {
+            continue;
+        }
+        done[v] = true;
+        let d = paths.distance[v].unwrap();
+        for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+            let zero = w == W::default();
+            if !zero {
+                match unit {
+                    None => {
+                        assert!(w > W::default(), "0-1 BFS does not support negative weights");
+                        unit = Some(w);
+                    }
+                    Some(one) => assert!(one == w, "0-1 BFS supports only one non-zero weight"),
+                }
+            }
+            let candidate = d + w;
+            if paths.distance[u].is_none_or(|du| candidate < du) {
+                paths.distance[u] = Some(candidate);
+                paths.predecessor[u] = Some(v);
+                if zero {
+                    deque.push_front(u);
+                } else {
+                    deque.push_back(u);
+                }
+            }
+        }
+    }
+    paths
+}
+
+/**
+Finds shortest paths from the source in a graph with any weights using the Bellman–Ford algorithm.
+
+Returns an error with vertices of a negative cycle reachable from the source (in the order of edges)
+if shortest paths are not defined. In undirected graphs every edge with a negative weight is such a cycle.
+
+**More:** <https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V * E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, bellman_ford};
+
+fn main(){
+    let graph = DiGraph::with_weights(4, &[(0, 1, 4), (0, 2, 5), (2, 1, -3), (1, 3, 2)]);
+    let paths = bellman_ford(&graph, 0).unwrap();
+    assert_eq!(paths.distance, vec![Some(0), Some(2), Some(5), Some(4)]);
+
+    let graph = DiGraph::with_weights(4, &[(0, 1, 1), (1, 2, -2), (2, 3, 1), (3, 1, -1)]);
+    assert_eq!(bellman_ford(&graph, 0).unwrap_err(), vec![2, 3, 1]);
+}
+```
+*/
+pub fn bellman_ford<G, W>(graph: &G, source: usize) -> Result<ShortestPaths<W>, Vec<usize>>
+    where
+        G: Adjacency<Weight = W>,
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut paths = ShortestPaths::new(n, source);
+    for round in 0..n {
+        let mut last_relaxed = None;
+        for v in 0..n {
+            let d = match paths.distance[v] {
+                Some(d) => d,
+                None => continue,
+            };
+            for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+                let candidate = d + w;
+                if paths.distance[u].is_none_or(|du| candidate < du) {
+                    paths.distance[u] = Some(candidate);
+                    paths.predecessor[u] = Some(v);
+                    last_relaxed = Some(u);
+                }
+            }
+        }
+        match last_relaxed {
+            None => break,
+            //distances still change after V - 1 rounds
+            Some(u) if round + 1 == n => {
+                return Err(predecessor_cycle(&paths.predecessor, u).expect("Relaxation in the last round implies a negative cycle"));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(paths)
+}
+
+/**
+Finds shortest paths from the source in a graph with any weights using the Shortest Path Faster Algorithm.
+
+This is a variant of the Bellman–Ford algorithm that relaxes only edges leaving vertices whose distances
+have changed, which is usually much faster. Results are the same as in ```bellman_ford()```.
+
+**More:** <https://en.wikipedia.org/wiki/Shortest_path_faster_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V * E), on average O(E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, spfa};
+
+fn main(){
+    let graph = DiGraph::with_weights(3, &[(0, 1, 5), (1, 2, -2), (0, 2, 4)]);
+    let paths = spfa(&graph, 0).unwrap();
+    assert_eq!(paths.distance, vec![Some(0), Some(5), Some(3)]);
+    assert_eq!(paths.path_to(2), Some(vec![0, 1, 2]));
+}
+```
+*/
+pub fn spfa<G, W>(graph: &G, source: usize) -> Result<ShortestPaths<W>, Vec<usize>>
+    where
+        G: Adjacency<Weight = W>,
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut paths = ShortestPaths::new(n, source);
+    let mut queued = vec![false; n];
+    //number of edges on the current path, a path with V edges contains a negative cycle
+    let mut edges = vec![0usize; n];
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    queued[source] = true;
+    while let Some(v) = queue.pop_front() {
+        queued[v] = false;
+        let d = paths.distance[v].unwrap();
+        for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+            let candidate = d + w;
+            if paths.distance[u].is_none_or(|du| candidate < du) {
+                paths.distance[u] = Some(candidate);
+                paths.predecessor[u] = Some(v);
+                edges[u] = edges[v] + 1;
+                if edges[u] >= n {
+                    //the cycle is not always present among predecessors yet
+                    return Err(match predecessor_cycle(&paths.predecessor, u) {
+                        Some(cycle) => cycle,
+                        None => bellman_ford(graph, source).err().unwrap(),
+                    });
+                }
+                if !queued[u] {
+                    queued[u] = true;
+                    queue.push_back(u);
+                }
+            }
+        }
+    }
+    Ok(paths)
+}
+
+/**
+Finds shortest paths between all pairs of vertices using the Floyd–Warshall algorithm.
+
+Returns an error with vertices of a negative cycle (in the order of edges) if shortest paths are not defined.
+
+**More:** <https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V^3 + E)
+- Memory complexity: O(V^2)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, floyd_warshall};
+
+fn main(){
+    let graph = DiGraph::with_weights(3, &[(0, 1, 2), (1, 2, -1), (2, 0, 4)]);
+    let paths = floyd_warshall(&graph).unwrap();
+    assert_eq!(paths.distance[2], vec![Some(4), Some(6), Some(0)]);
+    assert_eq!(paths.path(1, 0), Some(vec![1, 2, 0]));
+    assert_eq!(paths.path(0, 0), Some(vec![0]));
+}
+```
+*/
+pub fn floyd_warshall<G, W>(graph: &G) -> Result<AllPairsShortestPaths<W>, Vec<usize>>
+    where
+        G: Adjacency<Weight = W>,
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut distance: Vec<Vec<Option<W>>> = vec![vec![None; n]; n];
+    let mut predecessor: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+    for (v, row) in distance.iter_mut().enumerate() {
+        row[v] = Some(W::default());
+        for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+            if row[u].is_none_or(|du| w < du) {
+                row[u] = Some(w);
+                predecessor[v][u] = Some(v);
+            }
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            let ik = match distance[i][k] {
+                Some(ik) => ik,
+                None => continue,
+            };
+            for j in 0..n {
+                if let Some(kj) = distance[k][j] {
+                    let candidate = ik + kj;
+                    if distance[i][j].is_none_or(|ij| candidate < ij) {
+                        distance[i][j] = Some(candidate);
+                        predecessor[i][j] = predecessor[k][j];
+                    }
+                }
+            }
+        }
+        //stop before lengths of walks around the cycle grow exponentially
+        if let Some(v) = (0..n).find(|&v| distance[v][v] < Some(W::default())) {
+            return Err(bellman_ford(graph, v).err().unwrap());
+        }
+    }
+    Ok(AllPairsShortestPaths {
+        distance,
+        predecessor,
+    })
+}
+
+/**
+Finds the shortest path from the source to the target using the A* search algorithm.
+
+The heuristic estimates the distance from a vertex to the target. It must be admissible
+(never overestimate the distance), otherwise the result may not be optimal. The search stops when
+the target is reached: its distance is exact, distances of other vertices are only upper bounds.
+Panics if the search reaches an edge with a negative weight.
+
+**More:** <https://en.wikipedia.org/wiki/A*_search_algorithm>
+
+# Complexity
+
+- Processing complexity: O((V + E) * log(V)) for consistent heuristics, usually much better than Dijkstra's algorithm
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, a_star};
+
+fn main(){
+    //a 4x4 grid with Manhattan distance as the heuristic
+    let mut edges = Vec::new();
+    for v in 0..16 {
+        if v % 4 < 3 { edges.push((v, v + 1, 1)); }
+        if v < 12 { edges.push((v, v + 4, 1)); }
+    }
+    let graph = Graph::with_weights(16, &edges);
+    let manhattan = |v: usize| (3 - v / 4) + (3 - v % 4);
+    let paths = a_star(&graph, 0, 15, manhattan);
+    assert_eq!(paths.distance[15], Some(6));
+    assert_eq!(paths.path_to(15).unwrap().len(), 7);
+}
+```
+*/
+pub fn a_star<G, W, H>(graph: &G, source: usize, target: usize, mut heuristic: H) -> ShortestPaths<W>
+    where
+        G: Adjacency<Weight = W>,
+        W: Copy + Ord + Add<Output = W> + Default,
+        H: FnMut(usize) -> W,
+{
+    let mut paths = ShortestPaths::new(graph.vertex_count(), source);
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(source), W::default(), source)));
+    while let Some(Reverse((_, d, v))) = heap.pop() {
+        if paths.distance[v] != Some(d) {
+            continue;
+        }
+        if v == target {
+            break;
+        }
+        for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+            assert!(w >= W::default(), "A* search does not support negative weights");
+            let candidate = d + w;
+            if paths.distance[u].is_none_or(|du| candidate < du) {
+                paths.distance[u] = Some(candidate);
+                paths.predecessor[u] = Some(v);
+                heap.push(Reverse((candidate + heuristic(u), candidate, u)));
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::{Graph, DiGraph};
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    fn random_edges(rng: &mut XorShiftRng, n: usize, m: usize, min: i64, max: i64) -> Vec<(usize, usize, i64)> {
+        (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n), rng.gen_range(min, max))).collect()
+    }
+
+    //weight of the lightest edge between two vertices
+    fn lightest<G: Adjacency<Weight = i64>>(graph: &G, from: usize, to: usize) -> i64 {
+        graph.neighbors(from).iter().zip(graph.weights(from))
+            .filter(|&(&t, _)| t == to).map(|(_, &w)| w).min().unwrap()
+    }
+
+    //every step of the path uses an edge that agrees with distances
+    fn check_paths<G: Adjacency<Weight = i64>>(graph: &G, paths: &ShortestPaths<i64>) {
+        for v in 0..graph.vertex_count() {
+            if let Some(u) = paths.predecessor[v] {
+                assert_eq!(paths.distance[v], Some(paths.distance[u].unwrap() + lightest(graph, u, v)));
+            }
+        }
+    }
+
+    #[test]
+    fn non_negative_algorithms_agree() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 25);
+            let m = rng.gen_range(0, 60);
+            let edges = random_edges(&mut rng, n, m, 0, 10);
+            let graph = DiGraph::with_weights(n, &edges);
+            let all = floyd_warshall(&graph).unwrap();
+            for source in 0..n {
+                let expected = &all.distance[source];
+                let paths = dijkstra(&graph, source);
+                check_paths(&graph, &paths);
+                assert_eq!(&paths.distance, expected);
+                assert_eq!(&bellman_ford(&graph, source).unwrap().distance, expected);
+                assert_eq!(&spfa(&graph, source).unwrap().distance, expected);
+                for (target, &distance) in expected.iter().enumerate() {
+                    let found = a_star(&graph, source, target, |_| 0);
+                    assert_eq!(found.distance[target], distance);
+                    assert_eq!(found.path_to(target).is_some(), distance.is_some());
+                    if let Some(path) = all.path(source, target) {
+                        assert_eq!(path[0], source);
+                        assert_eq!(*path.last().unwrap(), target);
+                        let length: i64 = path.windows(2).map(|e| lightest(&graph, e[0], e[1])).sum();
+                        assert_eq!(Some(length), distance);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_one() {
+        let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 25);
+            let m = rng.gen_range(0, 60);
+            let edges: Vec<(usize, usize, i64)> = random_edges(&mut rng, n, m, 0, 2).into_iter()
+                .map(|(a, b, w)| (a, b, w * 3)).collect();
+            let graph = Graph::with_weights(n, &edges);
+            let source = rng.gen_range(0, n);
+            let paths = zero_one_bfs(&graph, source);
+            check_paths(&graph, &paths);
+            assert_eq!(paths.distance, dijkstra(&graph, source).distance);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_one_two_weights() {
+        zero_one_bfs(&DiGraph::with_weights(3, &[(0, 1, 1), (1, 2, 2)]), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dijkstra_negative() {
+        dijkstra(&DiGraph::with_weights(2, &[(0, 1, -1)]), 0);
+    }
+
+    fn check_cycle<G: Adjacency<Weight = i64>>(graph: &G, cycle: &[usize]) {
+        let mut total = 0;
+        for (i, &v) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            total += lightest(graph, v, next);
+        }
+        assert!(total < 0);
+    }
+
+    #[test]
+    fn negative_weights() {
+        let mut rng = XorShiftRng::from_seed([5, 6, 7, 8]);
+        let mut cycles = 0;
+        for _ in 0..300 {
+            let n = rng.gen_range(1, 15);
+            let m = rng.gen_range(0, 30);
+            let edges = random_edges(&mut rng, n, m, -3, 10);
+            let graph = DiGraph::with_weights(n, &edges);
+            let all = floyd_warshall(&graph);
+            if let Err(ref cycle) = all {
+                check_cycle(&graph, cycle);
+                cycles += 1;
+            }
+            for source in 0..n {
+                match (bellman_ford(&graph, source), spfa(&graph, source)) {
+                    (Ok(a), Ok(b)) => {
+                        check_paths(&graph, &a);
+                        check_paths(&graph, &b);
+                        assert_eq!(a.distance, b.distance);
+                        if let Ok(ref all) = all {
+                            assert_eq!(a.distance, all.distance[source]);
+                        }
+                    }
+                    (Err(a), Err(b)) => {
+                        assert!(all.is_err());
+                        check_cycle(&graph, &a);
+                        check_cycle(&graph, &b);
+                    }
+                    _ => panic!("Bellman-Ford and SPFA disagree"),
+                }
+            }
+        }
+        assert!(cycles > 10);
+    }
+
+    #[test]
+    fn undirected_negative_edge() {
+        let graph = Graph::with_weights(3, &[(0, 1, 2), (1, 2, -1)]);
+        let cycle = bellman_ford(&graph, 0).unwrap_err();
+        assert_eq!(cycle.len(), 2);
+        assert!(spfa(&graph, 0).is_err());
+        assert!(floyd_warshall(&graph).is_err());
+    }
+
+    #[test]
+    fn a_star_grid() {
+        let size = 30;
+        let mut rng = XorShiftRng::from_seed([1, 1, 1, 1]);
+        let mut edges = Vec::new();
+        for v in 0..size * size {
+            if v % size + 1 < size { edges.push((v, v + 1, rng.gen_range(1, 5))); }
+            if v + size < size * size { edges.push((v, v + size, rng.gen_range(1, 5))); }
+        }
+        let graph = Graph::with_weights(size * size, &edges);
+        let target = size * size - 1;
+        let manhattan = |v: usize| ((size - 1 - v / size) + (size - 1 - v % size)) as i64;
+        let found = a_star(&graph, 0, target, manhattan);
+        assert_eq!(found.distance[target], dijkstra(&graph, 0).distance[target]);
+    }
+}
@@ -0,0 +1,306 @@
+use super::{Adjacency, Graph, connected_components};
+use collections::FastDisjointSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::Add;
+
+/**
+Finds the minimum spanning forest of edges using Kruskal's algorithm.
+
+Edges are processed in the order of increasing weights and an edge is chosen if it joins two different trees,
+which is checked using ```FastDisjointSet```. Vertices can be of any hashable type.
+Ties are broken by edge indexes, so ```kruskal()```, ```prim()```, ```boruvka()``` and ```minimum_spanning_forest()```
+choose the same edges.
+
+Returns indexes of chosen edges in the order of choosing and their total weight.
+
+**More:** <https://en.wikipedia.org/wiki/Kruskal%27s_algorithm>
+
+# Complexity
+
+- Processing complexity: O(E * log(E))
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::kruskal;
+
+fn main(){
+    let roads = [("A", "B", 7), ("A", "C", 3), ("B", "C", 2), ("C", "D", 6), ("B", "D", 4)];
+    assert_eq!(kruskal(&roads), (vec![2, 1, 4], 9));
+}
+```
+*/
+pub fn kruskal<T, W>(edges: &[(T, T, W)]) -> (Vec<usize>, W)
+    where
+        T: Eq + Hash + Clone,
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    //stable sorting keeps ties ordered by indexes
+    order.sort_by_key(|&i| edges[i].2);
+    let mut trees = FastDisjointSet::default();
+    let mut chosen = Vec::new();
+    let mut total = W::default();
+    for id in order {
+        let (ref a, ref b, weight) = edges[id];
+        if a == b || trees.in_union(a, b) {
+            continue;
+        }
+        trees.union(a.clone(), b.clone());
+        chosen.push(id);
+        total = total + weight;
+    }
+    (chosen, total)
+}
+
+//grows the tree containing the root, vertices already in other trees are skipped
+fn prim_from<W>(graph: &Graph<W>, root: usize, in_tree: &mut [bool], chosen: &mut Vec<usize>, total: &mut W)
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut heap = BinaryHeap::new();
+    in_tree[root] = true;
+    let mut v = root;
+    loop {
+        for ((&u, &w), &id) in graph.neighbors(v).iter().zip(graph.weights(v)).zip(graph.edge_ids(v)) {
+            if !in_tree[u] {
+                heap.push(Reverse((w, id, u)));
+            }
+        }
+        //the lightest edge leaving the tree
+        let next = loop {
+            match heap.pop() {
+                Some(Reverse((_, _, u))) if in_tree[u] => continue,
+                other => break other,
+            }
+        };
+        match next {
+            None => return,
+            Some(Reverse((w, id, u))) => {
+                in_tree[u] = true;
+                chosen.push(id);
+                *total = *total + w;
+                v = u;
+            }
+        }
+    }
+}
+
+/**
+Finds the minimum spanning tree of the component containing the root using Prim's algorithm.
+
+The tree grows from the root, always by the lightest edge leaving it, found using a binary heap.
+Ties are broken by edge indexes, see ```kruskal()```.
+
+Returns indexes of chosen edges in the order of choosing and their total weight.
+
+**More:** <https://en.wikipedia.org/wiki/Prim%27s_algorithm>
+
+# Complexity
+
+- Processing complexity: O(E * log(E))
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, prim};
+
+fn main(){
+    let graph = Graph::with_weights(5, &[(0, 1, 7), (0, 2, 3), (1, 2, 2), (2, 3, 6), (1, 3, 4), (4, 4, 1)]);
+    assert_eq!(prim(&graph, 0), (vec![1, 2, 4], 9));
+    assert_eq!(prim(&graph, 4), (vec![], 0));
+}
+```
+*/
+pub fn prim<W>(graph: &Graph<W>, root: usize) -> (Vec<usize>, W)
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut in_tree = vec![false; graph.vertex_count()];
+    let mut chosen = Vec::new();
+    let mut total = W::default();
+    prim_from(graph, root, &mut in_tree, &mut chosen, &mut total);
+    (chosen, total)
+}
+
+/**
+Finds the minimum spanning forest of a graph that does not need to be connected.
+
+Spanning trees of components are found using Prim's algorithm, starting from their smallest vertices.
+Ties are broken by edge indexes, see ```kruskal()```.
+
+Returns indexes of chosen edges in the order of choosing and their total weight.
+
+**More:** <https://en.wikipedia.org/wiki/Minimum_spanning_tree>
+
+# Complexity
+
+- Processing complexity: O(V + E * log(E))
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, minimum_spanning_forest};
+
+fn main(){
+    let graph = Graph::with_weights(5, &[(0, 1, 5), (3, 4, 2), (1, 2, 1), (0, 2, 3), (4, 3, 1)]);
+    assert_eq!(minimum_spanning_forest(&graph), (vec![3, 2, 4], 5));
+}
+```
+*/
+pub fn minimum_spanning_forest<W>(graph: &Graph<W>) -> (Vec<usize>, W)
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut in_tree = vec![false; n];
+    let mut chosen = Vec::new();
+    let mut total = W::default();
+    for root in 0..n {
+        if !in_tree[root] {
+            prim_from(graph, root, &mut in_tree, &mut chosen, &mut total);
+        }
+    }
+    (chosen, total)
+}
+
+/**
+Finds the minimum spanning forest using Borůvka's algorithm.
+
+In every round each tree of the forest chooses the lightest edge leaving it and all chosen edges are added at once.
+Trees are independent within a round, which makes the algorithm easy to parallelize.
+Ties are broken by edge indexes, see ```kruskal()```.
+
+Returns indexes of chosen edges in the order of choosing and their total weight.
+
+**More:** <https://en.wikipedia.org/wiki/Bor%C5%AFvka%27s_algorithm>
+
+# Complexity
+
+- Processing complexity: O((V + E) * log(V))
+- Memory complexity: O(V + E)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{Graph, boruvka};
+
+fn main(){
+    let graph = Graph::with_weights(4, &[(0, 1, 7), (0, 2, 3), (1, 2, 2), (2, 3, 6), (1, 3, 4)]);
+    assert_eq!(boruvka(&graph), (vec![1, 2, 4], 9));
+}
+```
+*/
+pub fn boruvka<W>(graph: &Graph<W>) -> (Vec<usize>, W)
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut forest: Vec<(usize, usize)> = Vec::new();
+    let mut chosen = Vec::new();
+    let mut is_chosen = vec![false; graph.edge_count()];
+    let mut total = W::default();
+    loop {
+        let (count, tree) = connected_components(&Graph::new(n, &forest));
+        //the lightest edge leaving every tree together with its ends
+        let mut lightest: Vec<Option<(W, usize, usize, usize)>> = vec![None; count];
+        for v in 0..n {
+            for ((&u, &w), &id) in graph.neighbors(v).iter().zip(graph.weights(v)).zip(graph.edge_ids(v)) {
+                let t = tree[v];
+                if tree[u] != t && lightest[t].is_none_or(|(best, best_id, _, _)| (w, id) < (best, best_id)) {
+                    lightest[t] = Some((w, id, v, u));
+                }
+            }
+        }
+        let before = chosen.len();
+        for (w, id, v, u) in lightest.into_iter().flatten() {
+            //an edge can be the lightest one for both its trees
+            if !is_chosen[id] {
+                is_chosen[id] = true;
+                chosen.push(id);
+                forest.push((v, u));
+                total = total + w;
+            }
+        }
+        if chosen.len() == before {
+            return (chosen, total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::has_cycle;
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    fn sorted(mut edges: Vec<usize>) -> Vec<usize> {
+        edges.sort();
+        edges
+    }
+
+    //the lightest forest among all subsets of edges that connect the same vertices
+    fn brute_force(n: usize, edges: &[(usize, usize, u32)]) -> u32 {
+        let (count, _) = connected_components(&Graph::with_weights(n, edges));
+        let mut best = None;
+        for mask in 0u32..1 << edges.len() {
+            let subset: Vec<(usize, usize)> = (0..edges.len()).filter(|i| mask & (1 << i) != 0).map(|i| (edges[i].0, edges[i].1)).collect();
+            if connected_components(&Graph::new(n, &subset)).0 == count {
+                let weight = (0..edges.len()).filter(|i| mask & (1 << i) != 0).map(|i| edges[i].2).sum();
+                if best.is_none_or(|b| weight < b) {
+                    best = Some(weight);
+                }
+            }
+        }
+        best.unwrap()
+    }
+
+    #[test]
+    fn small_graphs_brute_force() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 7);
+            let m = rng.gen_range(0, 10);
+            let edges: Vec<(usize, usize, u32)> = (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n), rng.gen_range(0, 5))).collect();
+            assert_eq!(kruskal(&edges).1, brute_force(n, &edges));
+        }
+    }
+
+    #[test]
+    fn algorithms_agree() {
+        let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 40);
+            let m = rng.gen_range(0, 80);
+            //few distinct weights to test tie breaking
+            let edges: Vec<(usize, usize, i32)> = (0..m).map(|_| (rng.gen_range(0, n), rng.gen_range(0, n), rng.gen_range(-3, 3))).collect();
+            let graph = Graph::with_weights(n, &edges);
+            let (expected, weight) = kruskal(&edges);
+            let pairs: Vec<(usize, usize)> = expected.iter().map(|&i| (edges[i].0, edges[i].1)).collect();
+            assert!(!has_cycle(&Graph::new(n, &pairs)));
+            assert_eq!(pairs.len(), n - connected_components(&graph).0);
+            let expected = sorted(expected);
+            let (forest, forest_weight) = minimum_spanning_forest(&graph);
+            assert_eq!((sorted(forest), forest_weight), (expected.clone(), weight));
+            let (forest, forest_weight) = boruvka(&graph);
+            assert_eq!((sorted(forest), forest_weight), (expected.clone(), weight));
+            //Prim's tree is a part of the forest
+            let root = rng.gen_range(0, n);
+            let (tree, _) = prim(&graph, root);
+            assert!(tree.iter().all(|id| expected.binary_search(id).is_ok()));
+        }
+    }
+
+    #[test]
+    fn kruskal_labels() {
+        let edges = [("x", "y", 1), ("y", "z", 1), ("z", "x", 1), ("x", "x", 0)];
+        assert_eq!(kruskal(&edges), (vec![0, 1], 2));
+        let empty: [(char, char, u8); 0] = [];
+        assert_eq!(kruskal(&empty), (vec![], 0));
+    }
+}
@@ -0,0 +1,219 @@
+use super::{Adjacency, DiGraph, find_cycle};
+use super::traversal::path_from_predecessors;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::Add;
+
+/**
+Sorts vertices of a directed acyclic graph topologically using Kahn's algorithm.
+
+In a topological order every edge goes from an earlier to a later vertex. Out of all such orders
+the lexicographically smallest one is returned: a vertex with no remaining incoming edges is taken from a min-heap.
+Returns an error with vertices of a cycle (in the order of edges) if the graph is not acyclic.
+
+**More:** <https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm>
+
+# Complexity
+
+- Processing complexity: O(V * log(V) + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, topological_sort};
+
+fn main(){
+    //task 3 has to be done before 1, tasks 2 and 1 before 0
+    let tasks = DiGraph::new(4, &[(3, 1), (1, 0), (2, 0)]);
+    assert_eq!(topological_sort(&tasks), Ok(vec![2, 3, 1, 0]));
+
+    let tasks = DiGraph::new(3, &[(0, 1), (1, 2), (2, 1)]);
+    assert_eq!(topological_sort(&tasks), Err(vec![1, 2]));
+}
+```
+*/
+pub fn topological_sort<W>(graph: &DiGraph<W>) -> Result<Vec<usize>, Vec<usize>> {
+    let n = graph.vertex_count();
+    let mut in_degree = vec![0usize; n];
+    for v in 0..n {
+        for &u in graph.neighbors(v) {
+            in_degree[u] += 1;
+        }
+    }
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..n).filter(|&v| in_degree[v] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(Reverse(v)) = ready.pop() {
+        order.push(v);
+        for &u in graph.neighbors(v) {
+            in_degree[u] -= 1;
+            if in_degree[u] == 0 {
+                ready.push(Reverse(u));
+            }
+        }
+    }
+    if order.len() == n {
+        Ok(order)
+    } else {
+        Err(find_cycle(graph).unwrap())
+    }
+}
+
+/**
+Finds the longest (critical) path in a directed acyclic graph with weighted edges.
+
+The path can start and end at any vertex. Returns the total weight and vertices of the first longest path found
+or an error with vertices of a cycle (in the order of edges) if the graph is not acyclic.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_path_problem#Acyclic_graphs>
+
+# Complexity
+
+- Processing complexity: O(V * log(V) + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, dag_longest_path};
+
+fn main(){
+    //durations of dependencies between build steps
+    let steps = DiGraph::with_weights(5, &[(0, 1, 3), (0, 2, 2), (1, 3, 4), (2, 3, 6), (3, 4, 1)]);
+    assert_eq!(dag_longest_path(&steps), Ok((9, vec![0, 2, 3, 4])));
+}
+```
+*/
+pub fn dag_longest_path<W>(graph: &DiGraph<W>) -> Result<(W, Vec<usize>), Vec<usize>>
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+{
+    let order = topological_sort(graph)?;
+    let n = graph.vertex_count();
+    //the longest path ending at every vertex
+    let mut length = vec![W::default(); n];
+    let mut predecessor = vec![None; n];
+    for &v in &order {
+        for (&u, &w) in graph.neighbors(v).iter().zip(graph.weights(v)) {
+            let candidate = length[v] + w;
+            if candidate > length[u] {
+                length[u] = candidate;
+                predecessor[u] = Some(v);
+            }
+        }
+    }
+    let mut best: Option<usize> = None;
+    for v in 0..n {
+        if best.is_none_or(|b| length[v] > length[b]) {
+            best = Some(v);
+        }
+    }
+    Ok(match best {
+        None => (W::default(), Vec::new()),
+        Some(end) => (length[end], path_from_predecessors(&predecessor, end)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    //edges only go from smaller to greater labels, vertices are relabeled by a random permutation
+    fn random_dag(rng: &mut XorShiftRng, n: usize, m: usize) -> Vec<(usize, usize, i32)> {
+        let mut label: Vec<usize> = (0..n).collect();
+        rng.shuffle(&mut label);
+        (0..m).filter_map(|_| {
+            let (a, b) = (rng.gen_range(0, n), rng.gen_range(0, n));
+            if a < b { Some((label[a], label[b], rng.gen_range(-2, 10))) } else { None }
+        }).collect()
+    }
+
+    fn next_permutation(arr: &mut [usize]) -> bool {
+        let i = match (1..arr.len()).rev().find(|&i| arr[i - 1] < arr[i]) {
+            Some(i) => i,
+            None => return false,
+        };
+        let j = (i..arr.len()).rev().find(|&j| arr[j] > arr[i - 1]).unwrap();
+        arr.swap(i - 1, j);
+        arr[i..].reverse();
+        true
+    }
+
+    #[test]
+    fn smallest_order_brute_force() {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 7);
+            let m = rng.gen_range(0, 12);
+            let edges: Vec<(usize, usize)> = random_dag(&mut rng, n, m).into_iter().map(|(a, b, _)| (a, b)).collect();
+            let graph = DiGraph::new(n, &edges);
+            //permutations are generated in the lexicographic order
+            let mut perm: Vec<usize> = (0..n).collect();
+            let expected = loop {
+                let mut position = vec![0; n];
+                for (i, &v) in perm.iter().enumerate() {
+                    position[v] = i;
+                }
+                if edges.iter().all(|&(a, b)| position[a] < position[b]) {
+                    break perm;
+                }
+                assert!(next_permutation(&mut perm));
+            };
+            assert_eq!(topological_sort(&graph), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 20);
+            let mut edges: Vec<(usize, usize, i32)> = random_dag(&mut rng, n, 2 * n);
+            let (a, b) = (rng.gen_range(0, n), rng.gen_range(0, n));
+            edges.push((a, b, 1));
+            let graph = DiGraph::with_weights(n, &edges);
+            match topological_sort(&graph) {
+                Ok(order) => {
+                    let mut position = vec![0; n];
+                    for (i, &v) in order.iter().enumerate() {
+                        position[v] = i;
+                    }
+                    assert!(edges.iter().all(|&(a, b, _)| position[a] < position[b]));
+                    assert!(dag_longest_path(&graph).is_ok());
+                }
+                Err(cycle) => {
+                    for (i, &v) in cycle.iter().enumerate() {
+                        assert!(graph.neighbors(v).contains(&cycle[(i + 1) % cycle.len()]));
+                    }
+                    assert_eq!(dag_longest_path(&graph), Err(cycle));
+                }
+            }
+        }
+    }
+
+    //the longest path starting at the vertex, checking all paths
+    fn longest_from(graph: &DiGraph<i32>, v: usize) -> i32 {
+        graph.neighbors(v).iter().zip(graph.weights(v))
+            .map(|(&u, &w)| w + longest_from(graph, u)).max().unwrap_or(0).max(0)
+    }
+
+    #[test]
+    fn longest_path_brute_force() {
+        let mut rng = XorShiftRng::from_seed([1, 1, 2, 2]);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 12);
+            let edges = random_dag(&mut rng, n, 3 * n);
+            let graph = DiGraph::with_weights(n, &edges);
+            let (length, path) = dag_longest_path(&graph).unwrap();
+            assert_eq!(length, (0..n).map(|v| longest_from(&graph, v)).max().unwrap());
+            let mut total = 0;
+            for step in path.windows(2) {
+                total += graph.neighbors(step[0]).iter().zip(graph.weights(step[0]))
+                    .filter(|&(&u, _)| u == step[1]).map(|(_, &w)| w).max().unwrap();
+            }
+            assert_eq!(total, length);
+        }
+        assert_eq!(dag_longest_path(&DiGraph::<i32>::with_weights(0, &[])), Ok((0, vec![])));
+    }
+}
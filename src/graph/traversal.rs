@@ -0,0 +1,305 @@
+use super::Adjacency;
+use std::collections::VecDeque;
+
+//walks back from the target using the predecessor array
+pub (crate) fn path_from_predecessors(predecessor: &[Option<usize>], target: usize) -> Vec<usize> {
+    let mut path = vec![target];
+    let mut curr = target;
+    while let Some(prev) = predecessor[curr] {
+        path.push(prev);
+        curr = prev;
+    }
+    path.reverse();
+    path
+}
+
+/**
+Result of the breadth-first search, created by ```bfs()```.
+*/
+#[derive(Clone, Debug)]
+pub struct BfsTree {
+    ///Reached vertices in the order of visiting.
+    pub order: Vec<usize>,
+    ///Number of edges on the shortest path from the source, None for unreachable vertices.
+    pub distance: Vec<Option<usize>>,
+    ///Previous vertex on the shortest path from the source, None for the source and unreachable vertices.
+    pub parent: Vec<Option<usize>>,
+}
+
+impl BfsTree {
+    /**
+    Returns vertices on the shortest path from the source to the target, None if the target is unreachable.
+
+    **Complexity:** O(length of the path)
+    */
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance[target].map(|_| path_from_predecessors(&self.parent, target))
+    }
+}
+
+/**
+Visits all vertices reachable from the source in the breadth-first order.
+
+Vertices are visited in the order of increasing distance (number of edges) from the source,
+so the search finds shortest paths in unweighted graphs.
+
+**More:** <https://en.wikipedia.org/wiki/Breadth-first_search>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, bfs};
+
+fn main(){
+    let graph = DiGraph::new(5, &[(0, 1), (1, 2), (0, 3), (3, 2), (2, 4)]);
+    let tree = bfs(&graph, 0);
+    assert_eq!(tree.order, vec![0, 1, 3, 2, 4]);
+    assert_eq!(tree.distance[4], Some(3));
+    assert_eq!(tree.path_to(4), Some(vec![0, 1, 2, 4]));
+}
+```
+*/
+pub fn bfs<G>(graph: &G, source: usize) -> BfsTree
+    where
+        G: Adjacency,
+{
+    let n = graph.vertex_count();
+    let mut distance = vec![None; n];
+    let mut parent = vec![None; n];
+    let mut order = Vec::new();
+    let mut queue = VecDeque::new();
+    distance[source] = Some(0);
+    queue.push_back(source);
+    while let Some(v) = queue.pop_front() {
+        order.push(v);
+        let next = distance[v].map(|d| d + 1);
+        for &u in graph.neighbors(v) {
+            if distance[u].is_none() {
+                distance[u] = next;
+                parent[u] = Some(v);
+                queue.push_back(u);
+            }
+        }
+    }
+    BfsTree {
+        order,
+        distance,
+        parent,
+    }
+}
+
+/**
+Event of the depth-first search, produced by ```Dfs```.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DfsEvent {
+    ///The vertex is entered (pre-order), together with the vertex it was reached from (None for roots).
+    Discover(usize, Option<usize>),
+    ///All vertices reachable from the vertex have been visited (post-order).
+    Finish(usize),
+}
+
+/**
+Iterator over events of the depth-first search, created by ```dfs()``` and ```dfs_forest()```.
+
+The search uses an explicit stack, so it works for graphs of any depth.
+*/
+#[derive(Clone, Debug)]
+pub struct Dfs<'a, G: 'a> {
+    graph: &'a G,
+    visited: Vec<bool>,
+    //vertex and the position of the next edge to check
+    stack: Vec<(usize, usize)>,
+    roots: Vec<usize>,
+    next_root: usize,
+}
+
+impl<'a, G> Iterator for Dfs<'a, G>
+    where
+        G: Adjacency,
+{
+    type Item = DfsEvent;
+
+    fn next(&mut self) -> Option<DfsEvent> {
+        if let Some(&(v, pos)) = self.stack.last() {
+            let graph = self.graph;
+            let neighbors = graph.neighbors(v);
+            let top = self.stack.len() - 1;
+            for (i, &u) in neighbors.iter().enumerate().skip(pos) {
+                if !self.visited[u] {
+                    self.stack[top].1 = i + 1;
+                    self.visited[u] = true;
+                    self.stack.push((u, 0));
+                    return Some(DfsEvent::Discover(u, Some(v)));
+                }
+            }
+            self.stack.pop();
+            return Some(DfsEvent::Finish(v));
+        }
+        while self.next_root < self.roots.len() {
+            let root = self.roots[self.next_root];
+            self.next_root += 1;
+            if !self.visited[root] {
+                self.visited[root] = true;
+                self.stack.push((root, 0));
+                return Some(DfsEvent::Discover(root, None));
+            }
+        }
+        None
+    }
+}
+
+pub (crate) fn dfs_from<'a, G>(graph: &'a G, roots: Vec<usize>) -> Dfs<'a, G>
+    where
+        G: Adjacency,
+{
+    Dfs {
+        graph,
+        visited: vec![false; graph.vertex_count()],
+        stack: Vec::new(),
+        roots,
+        next_root: 0,
+    }
+}
+
+/**
+Visits all vertices reachable from the source in the depth-first order.
+
+Returns an iterator of events: every reached vertex is discovered (pre-order) and later
+finished (post-order), after all vertices reachable from it have been finished.
+
+**More:** <https://en.wikipedia.org/wiki/Depth-first_search>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, dfs, DfsEvent};
+
+fn main(){
+    let graph = DiGraph::new(4, &[(0, 1), (1, 2), (0, 2), (3, 0)]);
+    let events: Vec<DfsEvent> = dfs(&graph, 0).collect();
+    assert_eq!(events, vec![
+        DfsEvent::Discover(0, None),
+        DfsEvent::Discover(1, Some(0)),
+        DfsEvent::Discover(2, Some(1)),
+        DfsEvent::Finish(2),
+        DfsEvent::Finish(1),
+        DfsEvent::Finish(0),
+    ]);
+}
+```
+*/
+pub fn dfs<'a, G>(graph: &'a G, source: usize) -> Dfs<'a, G>
+    where
+        G: Adjacency,
+{
+    dfs_from(graph, vec![source])
+}
+
+/**
+Visits all vertices of the graph in the depth-first order.
+
+Vertices that have not been visited yet become roots of new search trees in the increasing order.
+See ```dfs()``` for the description of events.
+
+**More:** <https://en.wikipedia.org/wiki/Depth-first_search>
+
+# Complexity
+
+- Processing complexity: O(V + E)
+- Memory complexity: O(V)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::graph::{DiGraph, dfs_forest, DfsEvent};
+
+fn main(){
+    let graph = DiGraph::new(3, &[(1, 0), (2, 1)]);
+    //post-order of a DAG reversed is a topological order
+    let mut order: Vec<usize> = dfs_forest(&graph).filter_map(|e| match e {
+        DfsEvent::Finish(v) => Some(v),
+        _ => None
+    }).collect();
+    order.reverse();
+    assert_eq!(order, vec![2, 1, 0]);
+}
+```
+*/
+pub fn dfs_forest<'a, G>(graph: &'a G) -> Dfs<'a, G>
+    where
+        G: Adjacency,
+{
+    dfs_from(graph, (0..graph.vertex_count()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::{Graph, DiGraph};
+
+    #[test]
+    fn bfs_unreachable() {
+        let graph = DiGraph::new(4, &[(0, 1), (2, 0), (1, 1)]);
+        let tree = bfs(&graph, 0);
+        assert_eq!(tree.order, vec![0, 1]);
+        assert_eq!(tree.distance, vec![Some(0), Some(1), None, None]);
+        assert_eq!(tree.path_to(2), None);
+        assert_eq!(tree.path_to(0), Some(vec![0]));
+    }
+
+    #[test]
+    fn bfs_grid() {
+        //3x3 grid
+        let mut edges = Vec::new();
+        for r in 0..3 {
+            for c in 0..3 {
+                if c < 2 { edges.push((3 * r + c, 3 * r + c + 1)); }
+                if r < 2 { edges.push((3 * r + c, 3 * r + c + 3)); }
+            }
+        }
+        let graph = Graph::new(9, &edges);
+        let tree = bfs(&graph, 4);
+        let expected: Vec<Option<usize>> = [2, 1, 2, 1, 0, 1, 2, 1, 2].iter().map(|&d| Some(d)).collect();
+        assert_eq!(tree.distance, expected);
+        assert_eq!(tree.path_to(0).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn dfs_events_nested() {
+        let graph = Graph::new(5, &[(0, 1), (0, 2), (1, 3), (3, 0), (4, 4)]);
+        let events: Vec<DfsEvent> = dfs_forest(&graph).collect();
+        assert_eq!(events.len(), 10);
+        //every vertex is discovered before and finished after all its descendants
+        let mut open = Vec::new();
+        for event in events {
+            match event {
+                DfsEvent::Discover(v, parent) => {
+                    assert_eq!(parent, open.last().cloned());
+                    open.push(v);
+                }
+                DfsEvent::Finish(v) => assert_eq!(open.pop(), Some(v)),
+            }
+        }
+        assert!(open.is_empty());
+    }
+
+    #[test]
+    fn dfs_deep() {
+        let n = 200_000;
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+        let graph = DiGraph::new(n, &edges);
+        assert_eq!(dfs(&graph, 0).count(), 2 * n);
+        assert_eq!(dfs(&graph, n - 1).count(), 2);
+    }
+}
@@ -19,20 +19,53 @@ The following algorithms are already implemented:
 - Finding first unsorted element.
 - Checking if the collection is sorted.
 - Counting sort.
-- Longest sorted subsequence.
-- Longest sorted substring.
+- Longest sorted and bitonic subsequences, their counts and lengths ending at every element.
+- Longest sorted substring, maximal runs and two-pointer search for the longest substring satisfying a condition.
+- Argsort, ranking and permutations.
+- Sortedness metrics (inversions, Kendall tau distance, runs).
+- Permutations, combinations, power sets and Cartesian products.
 
 # Searching
 
 - Binary search.
-- Interpolation search.
+- Lower bound, upper bound and equal range.
+- Exponential and galloping search, unbounded search over monotone predicates.
+- Binary search on the answer (integer ranges, bisection, parallel binary search).
+- Ternary and golden-section search for unimodal functions.
+- Cache-friendly static search tables (Eytzinger and B-tree layouts).
+- Interpolation search (including a hybrid variant with O(log(n)) worst case).
 - Quick select.
 
+# Strings
+
+- Exact string matching (Knuth–Morris–Pratt, Z-function, Boyer–Moore–Horspool, Rabin–Karp).
+- Multi-pattern matching (Aho–Corasick).
+- Suffix arrays, LCP arrays, longest repeated and longest common substrings.
+- Suffix automaton with online extension and occurrence counting.
+- Palindromic substrings (Manacher's algorithm).
+- Edit distances (Levenshtein, Damerau–Levenshtein, Hamming, weighted) with alignments.
+- Longest common subsequence (dynamic programming, Hunt–Szymanski) and Myers' diff with unified output.
+
 # Collections
 
 - Disjoint set (also known as Union Find).
 - Counter.
 
+# Graphs
+
+- Compact graph representation (CSR), breadth-first and depth-first search.
+- Connected components, bipartiteness and cycle detection.
+- Shortest paths (Dijkstra, 0-1 BFS, Bellman–Ford, SPFA, Floyd–Warshall, A*).
+- Minimum spanning trees and forests (Kruskal, Prim, Borůvka).
+- Strongly connected components (Tarjan, Kosaraju), condensation, topological sorting and longest paths in DAGs.
+- Bridges, articulation points, 2-edge-connected and biconnected components, block-cut trees.
+- Maximum flow (Dinic, highest-label push-relabel), minimum cut and flows with lower bounds.
+
+# Random sampling
+
+- Reservoir sampling, also as an extension of iterators.
+- Weighted sampling (alias method, weighted reservoir sampling).
+
 */
 
 extern crate fnv;
@@ -42,3 +75,6 @@ pub mod search;
 pub mod collections;
 pub mod math;
 pub mod utils;
+pub mod random;
+pub mod strings;
+pub mod graph;
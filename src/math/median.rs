@@ -1,4 +1,5 @@
-use super::super::search::{quick_select, quick_select_rand};
+use super::super::search::{quick_select, quick_select_rand, quick_select_rand_with_rng};
+use rand::Rng;
 use std::ops::{Add, Div};
 
 #[inline(always)]
@@ -93,6 +94,45 @@ where
     quick_select_rand(arr, len / 2)
 }
 
+/**
+Calculates median using the randomized quick select algorithm and the provided random number generator.
+
+This is the same algorithm as ```median_rand()```, but pivots are chosen using the given
+generator, so a seeded generator makes the process reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Median>
+
+# Complexity
+
+- Average processing complexity: O(n)
+- Worst processing complexity: O(n**2)
+- Memory complexity: 0(1)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::math::median_rand_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mut odd = [9, 2, 7, 3, 5, 4, 1, 6, 8 ];
+    assert_eq!(median_rand_with_rng(&mut odd, &mut rng), 5);
+}
+```
+*/
+pub fn median_rand_with_rng<T, R>(arr: &mut [T], rng: &mut R) -> T
+where
+    T: Clone + Ord,
+    R: Rng,
+{
+    let len = arr.len();
+    check_len(len);
+    quick_select_rand_with_rng(arr, len / 2, rng)
+}
+
 /**
 Calculates median using the quick select algorithm.
 
@@ -173,9 +213,48 @@ where
     median_avg_impl(arr, quick_select_rand)
 }
 
-fn median_avg_impl<T>(arr: &mut [T], qs: fn(&mut [T], usize) -> T) -> T
+/**
+Calculates median using the randomized quick select algorithm and the provided random number generator.
+
+If the array has an even number of elements, average of the middle two elements is returned.
+This is the same algorithm as ```median_avg_rand()```, but pivots are chosen using the given
+generator, so a seeded generator makes the process reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Median>
+
+# Complexity
+
+- Average processing complexity: O(n)
+- Worst processing complexity: O(n**2)
+- Memory complexity: 0(1)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::math::median_avg_rand_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mut even = [9, 2, 7, 3, 4, 1, 6, 8, 10, 0];
+    assert_eq!(median_avg_rand_with_rng(&mut even, &mut rng), 5);
+}
+```
+*/
+pub fn median_avg_rand_with_rng<T, R>(arr: &mut [T], rng: &mut R) -> T
+where
+    T: Clone + Ord + Add<Output = T> + Div<Output = T> + From<i32>,
+    R: Rng,
+{
+    median_avg_impl(arr, |a, n| quick_select_rand_with_rng(a, n, rng))
+}
+
+fn median_avg_impl<T, Q>(arr: &mut [T], qs: Q) -> T
 where
     T: Clone + Ord + Add<Output = T> + Div<Output = T> + From<i32>,
+    Q: FnOnce(&mut [T], usize) -> T,
 {
     let len = arr.len();
     check_len(len);
@@ -232,6 +311,16 @@ mod tests {
         assert_eq!(median_avg(&mut arr), 4);
     }
 
+    #[test]
+    fn seeded() {
+        use rand::{SeedableRng, XorShiftRng};
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let mut odd = [9, 2, 7, 3, 5, 4, 1, 6, 8];
+        assert_eq!(median_rand_with_rng(&mut odd, &mut rng), 5);
+        let mut even = [9, 2, 7, 3, 5, 4, 1, 6, 8, 0];
+        assert_eq!(median_avg_rand_with_rng(&mut even, &mut rng), 4);
+    }
+
     #[test]
     #[ignore]
     fn multiple_odd() {
@@ -0,0 +1,178 @@
+use rand::{Rng, thread_rng};
+use std::iter::FromIterator;
+
+/**
+Samples indexes with probabilities proportional to the provided weights.
+
+This is the Vose's variant of the alias method. After a linear preprocessing every sample
+requires generating just two random numbers, no matter how many weights there are.
+
+Panics if there are no weights, if any weight is negative or not finite, or if all weights are 0.
+
+**More:** <https://en.wikipedia.org/wiki/Alias_method>
+
+# Complexity
+
+- Initialization complexity: O(n)
+- Sampling complexity: O(1)
+- Memory complexity: O(n)
+
+# Example
+
+```
+extern crate algorithm;
+use algorithm::random::AliasTable;
+
+fn main(){
+    //index 1 is three times more likely than index 0, index 2 is never chosen
+    let table = AliasTable::new(&[1.0, 3.0, 0.0]);
+    let idx = table.sample();
+    assert!(idx == 0 || idx == 1);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    ///Creates a new alias table from the provided weights.
+    pub fn new(weights: &[f64]) -> Self {
+        if weights.is_empty() {
+            panic!("Cannot create alias table without weights.");
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            panic!("Weights must be finite and not negative.");
+        }
+        let sum: f64 = weights.iter().sum();
+        if sum <= 0.0 {
+            panic!("At least one weight must be positive.");
+        }
+        let len = weights.len();
+        //scale weights so that the average is 1
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w * len as f64 / sum).collect();
+        let mut prob = vec![0.0; len];
+        let mut alias: Vec<usize> = (0..len).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..len).partition(|&i| scaled[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            large.pop();
+            //the column of s is filled up with l
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] = scaled[l] + scaled[s] - 1.0;
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+        //remaining columns are full, leftovers in small are caused by rounding errors
+        for i in large.into_iter().chain(small) {
+            prob[i] = 1.0;
+        }
+        Self { prob, alias }
+    }
+
+    ///Returns the number of weights.
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    ///Alias table always has at least one weight, so this always returns false.
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    /**
+    Draws a random index.
+
+    **Complexity:** O(1)
+    */
+    pub fn sample(&self) -> usize {
+        self.sample_with_rng(&mut thread_rng())
+    }
+
+    /**
+    Draws a random index using the provided random number generator.
+
+    **Complexity:** O(1)
+    */
+    pub fn sample_with_rng<R>(&self, rng: &mut R) -> usize
+        where
+            R: Rng,
+    {
+        let column = rng.gen_range(0, self.prob.len());
+        if rng.gen::<f64>() < self.prob[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+}
+
+impl FromIterator<f64> for AliasTable {
+    ///Creates alias table from the provided weights.
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let weights: Vec<f64> = iter.into_iter().collect();
+        Self::new(&weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, XorShiftRng};
+
+    fn frequencies(table: &AliasTable, samples: usize) -> Vec<f64> {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let mut counts = vec![0usize; table.len()];
+        for _ in 0..samples {
+            counts[table.sample_with_rng(&mut rng)] += 1;
+        }
+        counts.into_iter().map(|c| c as f64 / samples as f64).collect()
+    }
+
+    #[test]
+    fn single() {
+        let table = AliasTable::new(&[5.0]);
+        assert_eq!(table.sample(), 0);
+    }
+
+    #[test]
+    fn proportional() {
+        let table = AliasTable::new(&[1.0, 2.0, 3.0, 4.0]);
+        let freq = frequencies(&table, 100000);
+        for (f, expected) in freq.iter().zip(&[0.1, 0.2, 0.3, 0.4]) {
+            assert!((f - expected).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn zero_weight() {
+        let table: AliasTable = vec![0.0, 1.0, 0.0, 1.0].into_iter().collect();
+        let freq = frequencies(&table, 10000);
+        assert_eq!(freq[0], 0.0);
+        assert_eq!(freq[2], 0.0);
+    }
+
+    #[should_panic]
+    #[test]
+    fn empty() {
+        AliasTable::new(&[]);
+    }
+
+    #[should_panic]
+    #[test]
+    fn negative() {
+        AliasTable::new(&[1.0, -1.0]);
+    }
+
+    #[should_panic]
+    #[test]
+    fn all_zero() {
+        AliasTable::new(&[0.0, 0.0]);
+    }
+}
@@ -0,0 +1,148 @@
+use collections::Counter;
+use super::AliasTable;
+use rand::{Rng, thread_rng};
+use std::hash::{BuildHasher, Hash};
+
+/**
+Draws elements of a Counter with probabilities proportional to their counts.
+
+This makes it possible to build a frequency table from data and then generate new data
+with the same distribution. The sampler is a snapshot - later changes of the counter
+are not reflected.
+
+Panics if the counter is empty.
+
+**More:** <https://en.wikipedia.org/wiki/Alias_method>
+
+# Complexity
+
+- Initialization complexity: O(k)
+- Sampling complexity: O(1)
+- Memory complexity: O(k)
+
+where k - number of unique elements in the counter.
+
+# Example
+
+```
+extern crate algorithm;
+use algorithm::collections::Counter;
+use algorithm::random::CounterSampler;
+use std::iter::FromIterator;
+
+fn main(){
+    let counter: Counter<char> = Counter::from_iter("aaab".chars());
+    let sampler = CounterSampler::new(&counter);
+    //'a' is drawn 3 times more often than 'b'
+    let c = *sampler.sample();
+    assert!(c == 'a' || c == 'b');
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct CounterSampler<T> {
+    keys: Vec<T>,
+    table: AliasTable,
+}
+
+impl<T> CounterSampler<T> {
+    ///Creates a new sampler from the provided counter.
+    pub fn new<S>(counter: &Counter<T, S>) -> Self
+        where
+            T: Hash + Eq + Clone,
+            S: BuildHasher,
+    {
+        let (keys, weights): (Vec<T>, Vec<f64>) = counter.iter()
+            .map(|(key, &count)| (key.clone(), count as f64))
+            .unzip();
+        Self::from_parts(keys, weights)
+    }
+
+    fn from_parts(keys: Vec<T>, weights: Vec<f64>) -> Self {
+        if keys.is_empty() {
+            panic!("Cannot sample from an empty counter.");
+        }
+        Self {
+            keys,
+            table: AliasTable::new(&weights),
+        }
+    }
+
+    ///Returns the number of unique elements that can be drawn.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    ///Sampler always has at least one element, so this always returns false.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /**
+    Draws a random element.
+
+    **Complexity:** O(1)
+    */
+    pub fn sample(&self) -> &T {
+        self.sample_with_rng(&mut thread_rng())
+    }
+
+    /**
+    Draws a random element using the provided random number generator.
+
+    **Complexity:** O(1)
+    */
+    pub fn sample_with_rng<R>(&self, rng: &mut R) -> &T
+        where
+            R: Rng,
+    {
+        &self.keys[self.table.sample_with_rng(rng)]
+    }
+}
+
+impl<T, S> From<Counter<T, S>> for CounterSampler<T>
+    where
+        T: Hash + Eq,
+        S: BuildHasher,
+{
+    ///Creates a new sampler, consuming the provided counter.
+    fn from(counter: Counter<T, S>) -> Self {
+        let (keys, weights): (Vec<T>, Vec<f64>) = counter.into_iter()
+            .map(|(key, count)| (key, count as f64))
+            .unzip();
+        Self::from_parts(keys, weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::iter::FromIterator;
+    use rand::{SeedableRng, XorShiftRng};
+
+    #[test]
+    fn follows_counts() {
+        let counter: Counter<char> = Counter::from_iter("aaaabbbbbbcccccccccc".chars());
+        let sampler = CounterSampler::new(&counter);
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let drawn: Counter<char> = (0..20000).map(|_| *sampler.sample_with_rng(&mut rng)).collect();
+        assert!((drawn[&'a'] as f64 / 20000.0 - 0.2).abs() < 0.02);
+        assert!((drawn[&'b'] as f64 / 20000.0 - 0.3).abs() < 0.02);
+        assert!((drawn[&'c'] as f64 / 20000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn from_counter() {
+        let counter: Counter<i32> = Counter::from_iter(vec![7, 7]);
+        let sampler = CounterSampler::from(counter);
+        assert_eq!(sampler.len(), 1);
+        assert_eq!(*sampler.sample(), 7);
+    }
+
+    #[should_panic]
+    #[test]
+    fn empty() {
+        let counter: Counter<i32> = Counter::new();
+        CounterSampler::new(&counter);
+    }
+}
@@ -0,0 +1,13 @@
+/*!
+Random sampling algorithms.
+*/
+
+mod reservoir;
+mod alias;
+mod weighted;
+mod counter_sampler;
+
+pub use self::reservoir::{Reservoir, ReservoirSampling, reservoir_sample, reservoir_sample_with_rng};
+pub use self::alias::AliasTable;
+pub use self::weighted::{WeightedReservoir, weighted_sample, weighted_sample_with_rng};
+pub use self::counter_sampler::CounterSampler;
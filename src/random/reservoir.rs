@@ -0,0 +1,268 @@
+use rand::{Rng, ThreadRng, thread_rng};
+use std::iter::{Extend, IntoIterator};
+
+/**
+Uniformly samples k elements from a stream of unknown length.
+
+Elements are pushed one by one and only k of them are kept in memory.
+At any moment every element seen so far has the same probability of being in the sample.
+This is useful for sampling from iterators that are too long to be collected.
+
+The sampler uses ```rand::thread_rng()``` by default. Use ```with_rng()``` to provide
+a seeded generator and make the sample reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling>
+
+# Complexity
+
+- Push complexity: O(1)
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+use algorithm::random::Reservoir;
+
+fn main(){
+    let mut reservoir = Reservoir::new(3);
+    reservoir.extend(0..1000);
+    assert_eq!(reservoir.seen(), 1000);
+    assert_eq!(reservoir.sample().len(), 3);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct Reservoir<T, R = ThreadRng>
+    where
+        R: Rng,
+{
+    sample: Vec<T>,
+    capacity: usize,
+    seen: usize,
+    rng: R,
+}
+
+impl<T> Reservoir<T, ThreadRng> {
+    ///Creates a new reservoir that keeps up to k elements.
+    pub fn new(k: usize) -> Self {
+        Self::with_rng(k, thread_rng())
+    }
+}
+
+impl<T, R> Reservoir<T, R>
+    where
+        R: Rng,
+{
+    ///Creates a new reservoir that keeps up to k elements and uses the given random number generator.
+    pub fn with_rng(k: usize, rng: R) -> Self {
+        Self {
+            sample: Vec::with_capacity(k),
+            capacity: k,
+            seen: 0,
+            rng,
+        }
+    }
+
+    /**
+    Offers the next element of the stream to the reservoir.
+
+    **Complexity:** O(1)
+    */
+    pub fn push(&mut self, val: T) {
+        self.seen += 1;
+        if self.sample.len() < self.capacity {
+            self.sample.push(val);
+        } else {
+            //the new element replaces a random one with probability k/seen
+            let idx = self.rng.gen_range(0, self.seen);
+            if idx < self.capacity {
+                self.sample[idx] = val;
+            }
+        }
+    }
+
+    ///Returns the current sample. It has fewer than k elements only if fewer elements were seen.
+    pub fn sample(&self) -> &[T] {
+        &self.sample
+    }
+
+    ///Consumes the reservoir and returns the sample.
+    pub fn into_sample(self) -> Vec<T> {
+        self.sample
+    }
+
+    ///Returns the number of elements pushed so far.
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+}
+
+impl<T, R> Extend<T> for Reservoir<T, R>
+    where
+        R: Rng,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(val);
+        }
+    }
+}
+
+/**
+Uniformly samples k elements from the provided collection in a single pass.
+
+This is a convenience function built on top of ```Reservoir```.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+use algorithm::random::reservoir_sample;
+
+fn main(){
+    let lines = "a\nb\nc\nd\ne".lines();
+    let sample = reservoir_sample(lines, 2);
+    assert_eq!(sample.len(), 2);
+}
+```
+*/
+pub fn reservoir_sample<I, T>(iter: I, k: usize) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+{
+    reservoir_sample_with_rng(iter, k, thread_rng())
+}
+
+/**
+Uniformly samples k elements from the provided collection in a single pass using the given
+random number generator.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::random::reservoir_sample_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+    let a = reservoir_sample_with_rng(0..100, 5, XorShiftRng::from_seed([1, 2, 3, 4]));
+    let b = reservoir_sample_with_rng(0..100, 5, XorShiftRng::from_seed([1, 2, 3, 4]));
+    assert_eq!(a, b);
+}
+```
+*/
+pub fn reservoir_sample_with_rng<I, T, R>(iter: I, k: usize, rng: R) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+        R: Rng,
+{
+    let mut reservoir = Reservoir::with_rng(k, rng);
+    reservoir.extend(iter);
+    reservoir.into_sample()
+}
+
+/**
+Extension of iterators that samples k elements of the stream using ```Reservoir```.
+
+It is implemented for all iterators, so a sample can be taken at the end of a chain of adapters.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::random::ReservoirSampling;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+    let sample = (0..1000).filter(|x| x % 3 == 0).reservoir_sample(4);
+    assert_eq!(sample.len(), 4);
+    assert!(sample.iter().all(|x| x % 3 == 0));
+
+    let a = (0..100).reservoir_sample_with_rng(5, XorShiftRng::from_seed([1, 2, 3, 4]));
+    let b = (0..100).reservoir_sample_with_rng(5, XorShiftRng::from_seed([1, 2, 3, 4]));
+    assert_eq!(a, b);
+}
+```
+*/
+pub trait ReservoirSampling: Iterator + Sized {
+    ///Uniformly samples k elements of the iterator using ```rand::thread_rng()```.
+    fn reservoir_sample(self, k: usize) -> Vec<Self::Item> {
+        reservoir_sample(self, k)
+    }
+
+    ///Uniformly samples k elements of the iterator using the given random number generator.
+    fn reservoir_sample_with_rng<R>(self, k: usize, rng: R) -> Vec<Self::Item>
+        where
+            R: Rng,
+    {
+        reservoir_sample_with_rng(self, k, rng)
+    }
+}
+
+impl<I: Iterator> ReservoirSampling for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, XorShiftRng};
+
+    #[test]
+    fn short_stream() {
+        assert_eq!(reservoir_sample(vec![1, 2], 5), vec![1, 2]);
+        assert!(reservoir_sample(0..10, 0).is_empty());
+    }
+
+    #[test]
+    fn distinct_elements() {
+        let mut sample = reservoir_sample_with_rng(0..1000, 10, XorShiftRng::from_seed([1, 2, 3, 4]));
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 10);
+        assert!(sample.iter().all(|&e| e < 1000));
+    }
+
+    #[test]
+    fn iterator_extension() {
+        let sample = "a b c".split(' ').reservoir_sample(5);
+        assert_eq!(sample, vec!["a", "b", "c"]);
+        let expected = reservoir_sample_with_rng(0..1000, 10, XorShiftRng::from_seed([1, 2, 3, 4]));
+        assert_eq!((0..1000).reservoir_sample_with_rng(10, XorShiftRng::from_seed([1, 2, 3, 4])), expected);
+    }
+
+    #[test]
+    fn uniform() {
+        //every element of 0..10 should be picked roughly 1/10 of the time
+        let mut rng = XorShiftRng::from_seed([5, 6, 7, 8]);
+        let mut counts = [0usize; 10];
+        for _ in 0..10000 {
+            let mut reservoir = Reservoir::with_rng(1, &mut rng);
+            reservoir.extend(0..10);
+            counts[reservoir.sample()[0]] += 1;
+        }
+        assert!(counts.iter().all(|&c| c > 800 && c < 1200));
+    }
+}
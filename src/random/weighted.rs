@@ -0,0 +1,252 @@
+use rand::{Rng, ThreadRng, thread_rng};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+//element of the reservoir ordered by reversed key, so that BinaryHeap becomes a min-heap
+#[derive(Clone, Debug)]
+struct Keyed<T> {
+    key: f64,
+    val: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //keys are never NaN
+        other.key.partial_cmp(&self.key).unwrap()
+    }
+}
+
+/**
+Samples k elements without replacement from a weighted stream of unknown length.
+
+Every element is pushed together with its weight. The probability of an element being chosen
+before the others is proportional to its weight. Elements with weight 0 are never chosen.
+This is the A-Res algorithm by Efraimidis and Spirakis: every element gets a random key
+```u^(1/w)``` and the reservoir keeps k elements with the greatest keys.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling#Weighted_random_sampling>
+
+# Complexity
+
+- Push complexity: O(log(k))
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+use algorithm::random::WeightedReservoir;
+
+fn main(){
+    let mut reservoir = WeightedReservoir::new(2);
+    reservoir.push("rare", 1.0);
+    reservoir.push("common", 100.0);
+    reservoir.push("never", 0.0);
+    let mut sample = reservoir.into_sample();
+    sample.sort();
+    assert_eq!(sample, vec!["common", "rare"]);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct WeightedReservoir<T, R = ThreadRng>
+    where
+        R: Rng,
+{
+    heap: BinaryHeap<Keyed<T>>,
+    capacity: usize,
+    rng: R,
+}
+
+impl<T> WeightedReservoir<T, ThreadRng> {
+    ///Creates a new reservoir that keeps up to k elements.
+    pub fn new(k: usize) -> Self {
+        Self::with_rng(k, thread_rng())
+    }
+}
+
+impl<T, R> WeightedReservoir<T, R>
+    where
+        R: Rng,
+{
+    ///Creates a new reservoir that keeps up to k elements and uses the given random number generator.
+    pub fn with_rng(k: usize, rng: R) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(k),
+            capacity: k,
+            rng,
+        }
+    }
+
+    /**
+    Offers the next element of the stream with the given weight.
+
+    Panics if the weight is negative or not finite.
+
+    **Complexity:** O(log(k))
+    */
+    pub fn push(&mut self, val: T, weight: f64) {
+        if !weight.is_finite() || weight < 0.0 {
+            panic!("Weights must be finite and not negative.");
+        }
+        if weight == 0.0 || self.capacity == 0 {
+            return;
+        }
+        //ln(u^(1/w)) = ln(u)/w, logarithms do not change the order but avoid underflows
+        let u = 1.0 - self.rng.gen::<f64>();
+        let key = u.ln() / weight;
+        if self.heap.len() < self.capacity {
+            self.heap.push(Keyed { key, val });
+        } else if self.heap.peek().is_some_and(|min| key > min.key) {
+            self.heap.pop();
+            self.heap.push(Keyed { key, val });
+        }
+    }
+
+    ///Returns the number of elements currently in the sample.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    ///Checks if the sample is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    ///Consumes the reservoir and returns the sample, starting with the element chosen first.
+    pub fn into_sample(self) -> Vec<T> {
+        //ascending order of reversed keys means descending order of keys
+        self.heap.into_sorted_vec().into_iter().map(|k| k.val).collect()
+    }
+}
+
+/**
+Chooses k distinct indexes with probabilities proportional to the provided weights.
+
+Indexes are returned in the order in which they were drawn. Indexes with weight 0 are never chosen,
+so fewer than k indexes are returned if there are fewer than k positive weights.
+
+Panics if any weight is negative or not finite.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling#Weighted_random_sampling>
+
+# Complexity
+
+- Processing complexity: O(n*log(k))
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+use algorithm::random::weighted_sample;
+
+fn main(){
+    let weights = [5.0, 0.0, 1.0, 2.0];
+    let sample = weighted_sample(&weights, 2);
+    assert_eq!(sample.len(), 2);
+    assert!(!sample.contains(&1));
+}
+```
+*/
+pub fn weighted_sample(weights: &[f64], k: usize) -> Vec<usize> {
+    weighted_sample_with_rng(weights, k, thread_rng())
+}
+
+/**
+Chooses k distinct indexes with probabilities proportional to the provided weights
+using the given random number generator.
+
+Indexes are returned in the order in which they were drawn. Indexes with weight 0 are never chosen,
+so fewer than k indexes are returned if there are fewer than k positive weights.
+
+Panics if any weight is negative or not finite.
+
+**More:** <https://en.wikipedia.org/wiki/Reservoir_sampling#Weighted_random_sampling>
+
+# Complexity
+
+- Processing complexity: O(n*log(k))
+- Memory complexity: O(k)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::random::weighted_sample_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+    let weights = [5.0, 0.0, 1.0, 2.0];
+    let a = weighted_sample_with_rng(&weights, 2, XorShiftRng::from_seed([1, 2, 3, 4]));
+    let b = weighted_sample_with_rng(&weights, 2, XorShiftRng::from_seed([1, 2, 3, 4]));
+    assert_eq!(a, b);
+}
+```
+*/
+pub fn weighted_sample_with_rng<R>(weights: &[f64], k: usize, rng: R) -> Vec<usize>
+    where
+        R: Rng,
+{
+    let mut reservoir = WeightedReservoir::with_rng(k, rng);
+    for (idx, &w) in weights.iter().enumerate() {
+        reservoir.push(idx, w);
+    }
+    reservoir.into_sample()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, XorShiftRng};
+
+    #[test]
+    fn not_enough_positive() {
+        let sample = weighted_sample(&[0.0, 3.0, 0.0], 2);
+        assert_eq!(sample, vec![1]);
+    }
+
+    #[test]
+    fn distinct() {
+        let weights = [1.0; 20];
+        let mut sample = weighted_sample_with_rng(&weights, 10, XorShiftRng::from_seed([1, 2, 3, 4]));
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 10);
+    }
+
+    #[test]
+    fn first_is_proportional() {
+        //the first drawn index should follow the weights
+        let weights = [1.0, 3.0];
+        let mut rng = XorShiftRng::from_seed([5, 6, 7, 8]);
+        let mut first = 0;
+        for _ in 0..10000 {
+            if weighted_sample_with_rng(&weights, 2, &mut rng)[0] == 1 {
+                first += 1;
+            }
+        }
+        assert!(first > 7200 && first < 7800);
+    }
+
+    #[should_panic]
+    #[test]
+    fn negative() {
+        weighted_sample(&[1.0, -2.0], 1);
+    }
+}
@@ -0,0 +1,202 @@
+use std::ops::Range;
+use utils::Integer;
+
+/**
+Using binary search finds the first integer in the range for which the predicate is true.
+
+This is binary search on the answer: instead of a slice, the searched domain is a range of
+integers and the predicate is evaluated on demand. The predicate must flip only once:
+it is false for the beginning of the range and true for the end of the range
+(the same convention as in ```binary_first_by()```). Returns None if the predicate is
+false for the whole range. Works with any primitive integer type, the whole range of the
+type can be searched without overflows.
+
+**More:** <https://en.wikipedia.org/wiki/Binary_search_algorithm>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+where n - length of the range.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::first_true_in_range;
+fn main(){
+    //integer square root of 2000
+    let sqrt = first_true_in_range(0u64..2000, |x| x*x > 2000).unwrap() - 1;
+    assert_eq!(sqrt, 44);
+    assert_eq!(first_true_in_range(-50i32..50, |x| x >= -7), Some(-7));
+    assert_eq!(first_true_in_range(-50i32..50, |x| x >= 50), None);
+}
+```
+*/
+pub fn first_true_in_range<T, F>(range: Range<T>, mut pred: F) -> Option<T>
+    where
+        T: Integer,
+        F: FnMut(T) -> bool,
+{
+    let Range { start: mut lo, end } = range;
+    let mut hi = end;
+    //invariant: the result is in [lo, hi], hi means no result
+    while lo < hi {
+        let mid = T::midpoint(lo, hi);
+        if pred(mid) {
+            hi = mid;
+        } else {
+            //mid < hi so this never overflows
+            lo = match mid.checked_add(T::one()) {
+                Some(next) => next,
+                None => hi,
+            };
+        }
+    }
+    if lo >= end {
+        None
+    } else {
+        Some(lo)
+    }
+}
+
+/**
+Stop condition of the ```bisect()``` and ```golden_section_search()``` functions.
+*/
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BisectLimit {
+    ///Stop when the searched range is not longer than the given value.
+    Epsilon(f64),
+    ///Stop after the given number of halvings.
+    Iterations(u32),
+}
+
+//validates the searched range and converts the limit into epsilon and the number of iterations
+pub (crate) fn validate_limit(lo: f64, hi: f64, limit: BisectLimit) -> (f64, u32) {
+    if !lo.is_finite() || !hi.is_finite() || lo > hi {
+        panic!("Range must be finite and lo must not be greater than hi.");
+    }
+    match limit {
+        BisectLimit::Epsilon(eps) => {
+            if eps.is_nan() || eps <= 0.0 {
+                panic!("Epsilon must be positive.");
+            }
+            (eps, u32::MAX)
+        }
+        BisectLimit::Iterations(iterations) => (0.0, iterations),
+    }
+}
+
+/**
+Using the bisection method finds the point in the range where the predicate becomes true.
+
+The predicate must flip only once: it is false for the beginning of the range and true
+for the end of the range (the same convention as in ```binary_first_by()```).
+The returned value is the end of the narrowed range, so the predicate is true for it
+(unless it is false for the whole range, then ```hi``` is returned).
+The search also stops when the range cannot be split any more because of the limited
+precision of floating-point numbers, so a very small epsilon is safe.
+
+Panics if ```lo > hi```, if any of them is not finite, or if epsilon is not positive.
+
+**More:** <https://en.wikipedia.org/wiki/Bisection_method>
+
+# Complexity
+
+- Processing complexity: O(log((hi-lo)/eps)) or O(iterations)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::{bisect, BisectLimit};
+fn main(){
+    //square root of 2
+    let sqrt = bisect(0.0, 2.0, |x| x*x >= 2.0, BisectLimit::Epsilon(1e-9));
+    assert!((sqrt - 2f64.sqrt()).abs() < 1e-9);
+    //40 halvings of the range [0, 1] give precision better than 1e-12
+    let third = bisect(0.0, 1.0, |x| 3.0*x >= 1.0, BisectLimit::Iterations(40));
+    assert!((third - 1.0/3.0).abs() < 1e-12);
+}
+```
+*/
+pub fn bisect<F>(lo: f64, hi: f64, mut pred: F, limit: BisectLimit) -> f64
+    where
+        F: FnMut(f64) -> bool,
+{
+    let (eps, iterations) = validate_limit(lo, hi, limit);
+    let (mut lo, mut hi) = (lo, hi);
+    for _ in 0..iterations {
+        if hi - lo <= eps {
+            break;
+        }
+        //this form does not overflow for big values of opposite signs
+        let mid = lo / 2.0 + hi / 2.0;
+        if mid <= lo || mid >= hi {
+            //no more representable values between lo and hi
+            break;
+        }
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_every_threshold() {
+        for threshold in 0..40i32 {
+            assert_eq!(first_true_in_range(3..37, |x| x >= threshold), if threshold < 37 { Some(threshold.max(3)) } else { None });
+        }
+    }
+
+    #[test]
+    fn range_empty() {
+        assert_eq!(first_true_in_range(5u8..5, |_| true), None);
+        let (lo, hi) = (6u8, 5);
+        assert_eq!(first_true_in_range(lo..hi, |_| true), None);
+    }
+
+    #[test]
+    fn range_extremes() {
+        assert_eq!(first_true_in_range(i64::MIN..i64::MAX, |x| x >= i64::MAX - 1), Some(i64::MAX - 1));
+        assert_eq!(first_true_in_range(i64::MIN..i64::MAX, |_| true), Some(i64::MIN));
+        assert_eq!(first_true_in_range(0u8..255, |x| x == 255), None);
+    }
+
+    #[test]
+    fn bisect_all_false() {
+        assert_eq!(bisect(1.0, 2.0, |_| false, BisectLimit::Epsilon(1e-6)), 2.0);
+    }
+
+    #[test]
+    fn bisect_all_true() {
+        assert!(bisect(1.0, 2.0, |_| true, BisectLimit::Epsilon(1e-6)) - 1.0 < 1e-6);
+    }
+
+    #[test]
+    fn bisect_tiny_epsilon() {
+        //epsilon below the precision of f64 still terminates
+        let res = bisect(1e6, 1e7, |x| x >= 1234567.0, BisectLimit::Epsilon(1e-300));
+        assert_eq!(res, 1234567.0);
+    }
+
+    #[test]
+    fn bisect_iterations() {
+        let mut calls = 0;
+        bisect(0.0, 1.0, |x| { calls += 1; x > 0.3 }, BisectLimit::Iterations(10));
+        assert_eq!(calls, 10);
+    }
+
+    #[should_panic]
+    #[test]
+    fn bisect_reversed() {
+        bisect(2.0, 1.0, |_| true, BisectLimit::Iterations(10));
+    }
+}
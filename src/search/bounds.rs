@@ -0,0 +1,491 @@
+use std::cmp::Ordering;
+use sort::SortingOrder;
+
+//finds the first element for which to_left returns true or arr.len() if there is none
+//it is inlined so that the predicate gets inlined too
+#[inline(always)]
+pub (crate) fn partition_point_impl<T, F>(arr: &[T], mut to_left: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+{
+    let mut lo: usize = 0;
+    let mut hi: usize = arr.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        //this is safe, mid is always in the range [lo..hi)
+        if to_left(unsafe { arr.get_unchecked(mid) }) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+#[inline(always)]
+fn lower_bound_impl<T, F>(arr: &[T], mut cmp: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+{
+    partition_point_impl(arr, |e| cmp(e) != Ordering::Greater)
+}
+
+#[inline(always)]
+fn upper_bound_impl<T, F>(arr: &[T], mut cmp: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+{
+    partition_point_impl(arr, |e| cmp(e) == Ordering::Less)
+}
+
+#[inline(always)]
+fn equal_range_impl<T, F>(arr: &[T], mut cmp: F) -> (usize, usize)
+    where
+        F: FnMut(&T) -> Ordering,
+{
+    let from = lower_bound_impl(arr, &mut cmp);
+    //equal elements can only be found after the lower bound
+    let to = from + upper_bound_impl(&arr[from..], &mut cmp);
+    (from, to)
+}
+
+/**
+Finds the first position in a sorted slice where the searched value could be inserted using
+a provided comparator.
+
+The comparator follows the convention of ```binary_search_by()```: it returns ordering
+of the searched value relative to the given element. The result is the index of the first element
+that is not less than the searched value or ```arr.len()``` if there is no such element.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/lower_bound>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::lower_bound_by;
+
+fn main() {
+    let arr = [0, 3, 3, 3, 8, 11];
+    assert_eq!(lower_bound_by(&arr, |val| 3.cmp(val)), 1);
+    assert_eq!(lower_bound_by(&arr, |val| 12.cmp(val)), 6);
+}
+```
+*/
+pub fn lower_bound_by<T, F>(arr: &[T], cmp: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+{
+    lower_bound_impl(arr, cmp)
+}
+
+/**
+Finds the first position in a sorted slice where the searched key could be inserted, comparing
+keys extracted from elements.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/lower_bound>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::lower_bound_by_key;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let arr = [(1, 'a'), (3, 'b'), (3, 'c'), (7, 'd')];
+    assert_eq!(lower_bound_by_key(&arr, &3, |e| e.0, Ascending), 1);
+}
+```
+*/
+pub fn lower_bound_by_key<T, K, F>(arr: &[T], key: &K, mut f: F, order: SortingOrder) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+{
+    match order {
+        SortingOrder::Ascending => lower_bound_impl(arr, |e| key.cmp(&f(e))),
+        SortingOrder::Descending => lower_bound_impl(arr, |e| f(e).cmp(key)),
+    }
+}
+
+/**
+Finds the first position in a sorted slice where the value could be inserted.
+
+This is the index of the first element that is not before ```val``` in the given order,
+or ```arr.len()``` if there is no such element. Unlike ```binary_search()``` this function
+always returns a valid position, so it is suitable for maintaining sorted vectors.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/lower_bound>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::lower_bound;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let asc = [0, 3, 3, 3, 8, 11];
+    assert_eq!(lower_bound(&asc, &3, Ascending), 1);
+    assert_eq!(lower_bound(&asc, &4, Ascending), 4);
+
+    let desc = [11, 8, 3, 3, 3, 0];
+    assert_eq!(lower_bound(&desc, &3, Descending), 2);
+    assert_eq!(lower_bound(&desc, &-1, Descending), 6);
+}
+```
+*/
+pub fn lower_bound<T>(arr: &[T], val: &T, order: SortingOrder) -> usize
+    where
+        T: Ord,
+{
+    match order {
+        SortingOrder::Ascending => lower_bound_impl(arr, |e| val.cmp(e)),
+        SortingOrder::Descending => lower_bound_impl(arr, |e| e.cmp(val)),
+    }
+}
+
+/**
+Finds the last position in a sorted slice where the searched value could be inserted using
+a provided comparator.
+
+The comparator follows the convention of ```binary_search_by()```: it returns ordering
+of the searched value relative to the given element. The result is the index of the first element
+that is greater than the searched value or ```arr.len()``` if there is no such element.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/upper_bound>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::upper_bound_by;
+
+fn main() {
+    let arr = [0, 3, 3, 3, 8, 11];
+    assert_eq!(upper_bound_by(&arr, |val| 3.cmp(val)), 4);
+    assert_eq!(upper_bound_by(&arr, |val| (-1).cmp(val)), 0);
+}
+```
+*/
+pub fn upper_bound_by<T, F>(arr: &[T], cmp: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+{
+    upper_bound_impl(arr, cmp)
+}
+
+/**
+Finds the last position in a sorted slice where the searched key could be inserted, comparing
+keys extracted from elements.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/upper_bound>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::upper_bound_by_key;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let arr = [(1, 'a'), (3, 'b'), (3, 'c'), (7, 'd')];
+    assert_eq!(upper_bound_by_key(&arr, &3, |e| e.0, Ascending), 3);
+}
+```
+*/
+pub fn upper_bound_by_key<T, K, F>(arr: &[T], key: &K, mut f: F, order: SortingOrder) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+{
+    match order {
+        SortingOrder::Ascending => upper_bound_impl(arr, |e| key.cmp(&f(e))),
+        SortingOrder::Descending => upper_bound_impl(arr, |e| f(e).cmp(key)),
+    }
+}
+
+/**
+Finds the last position in a sorted slice where the value could be inserted.
+
+This is the index of the first element that is after ```val``` in the given order,
+or ```arr.len()``` if there is no such element.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/upper_bound>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::upper_bound;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let asc = [0, 3, 3, 3, 8, 11];
+    assert_eq!(upper_bound(&asc, &3, Ascending), 4);
+
+    let desc = [11, 8, 3, 3, 3, 0];
+    assert_eq!(upper_bound(&desc, &3, Descending), 5);
+}
+```
+*/
+pub fn upper_bound<T>(arr: &[T], val: &T, order: SortingOrder) -> usize
+    where
+        T: Ord,
+{
+    match order {
+        SortingOrder::Ascending => upper_bound_impl(arr, |e| val.cmp(e)),
+        SortingOrder::Descending => upper_bound_impl(arr, |e| e.cmp(val)),
+    }
+}
+
+/**
+Finds the range of elements equal to the searched value in a sorted slice using a provided comparator.
+
+Returns starting and ending indexes of the range. If no element is equal, the range is empty
+and both indexes point to the position where the value could be inserted.
+The comparator follows the convention of ```binary_search_by()```.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/equal_range>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::equal_range_by;
+
+fn main() {
+    let arr = [0, 3, 3, 3, 8, 11];
+    assert_eq!(equal_range_by(&arr, |val| 3.cmp(val)), (1, 4));
+    assert_eq!(equal_range_by(&arr, |val| 5.cmp(val)), (4, 4));
+}
+```
+*/
+pub fn equal_range_by<T, F>(arr: &[T], cmp: F) -> (usize, usize)
+    where
+        F: FnMut(&T) -> Ordering,
+{
+    equal_range_impl(arr, cmp)
+}
+
+/**
+Finds the range of elements with the searched key in a sorted slice, comparing keys extracted
+from elements.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/equal_range>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::equal_range_by_key;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let arr = [(7, 'a'), (3, 'b'), (3, 'c'), (1, 'd')];
+    assert_eq!(equal_range_by_key(&arr, &3, |e| e.0, Descending), (1, 3));
+}
+```
+*/
+pub fn equal_range_by_key<T, K, F>(arr: &[T], key: &K, mut f: F, order: SortingOrder) -> (usize, usize)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+{
+    match order {
+        SortingOrder::Ascending => equal_range_impl(arr, |e| key.cmp(&f(e))),
+        SortingOrder::Descending => equal_range_impl(arr, |e| f(e).cmp(key)),
+    }
+}
+
+/**
+Finds the range of elements equal to the value in a sorted slice.
+
+Returns starting and ending indexes of the range. If no element is equal, the range is empty
+and both indexes point to the position where the value could be inserted.
+
+**More:** <https://en.cppreference.com/w/cpp/algorithm/equal_range>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::equal_range;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let arr = ['a', 'b', 'b', 'c'];
+    let (from, to) = equal_range(&arr, &'b', Ascending);
+    assert_eq!(&arr[from..to], &['b', 'b']);
+}
+```
+*/
+pub fn equal_range<T>(arr: &[T], val: &T, order: SortingOrder) -> (usize, usize)
+    where
+        T: Ord,
+{
+    match order {
+        SortingOrder::Ascending => equal_range_impl(arr, |e| val.cmp(e)),
+        SortingOrder::Descending => equal_range_impl(arr, |e| e.cmp(val)),
+    }
+}
+
+/**
+Counts elements equal to the value in a sorted slice.
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::count_equal;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let arr = [1, 2, 2, 2, 5, 8];
+    assert_eq!(count_equal(&arr, &2, Ascending), 3);
+    assert_eq!(count_equal(&arr, &3, Ascending), 0);
+}
+```
+*/
+pub fn count_equal<T>(arr: &[T], val: &T, order: SortingOrder) -> usize
+    where
+        T: Ord,
+{
+    let (from, to) = equal_range(arr, val, order);
+    to - from
+}
+
+/**
+Finds the position where the value should be inserted to keep the slice sorted.
+
+The value is placed after all elements equal to it, so inserting elements one by one
+keeps equal elements in the order of insertion. This is the same as ```upper_bound()```.
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::insertion_point;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let mut v = vec![1, 3, 5];
+    let idx = insertion_point(&v, &4, Ascending);
+    v.insert(idx, 4);
+    assert_eq!(v, vec![1, 3, 4, 5]);
+}
+```
+*/
+pub fn insertion_point<T>(arr: &[T], val: &T, order: SortingOrder) -> usize
+    where
+        T: Ord,
+{
+    upper_bound(arr, val, order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::SortingOrder::*;
+
+    #[test]
+    fn empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(lower_bound(&arr, &5, Ascending), 0);
+        assert_eq!(upper_bound(&arr, &5, Ascending), 0);
+        assert_eq!(equal_range(&arr, &5, Descending), (0, 0));
+    }
+
+    #[test]
+    fn asc_bounds() {
+        let arr = [1, 2, 2, 2, 5, 8];
+        assert_eq!(lower_bound(&arr, &0, Ascending), 0);
+        assert_eq!(lower_bound(&arr, &2, Ascending), 1);
+        assert_eq!(upper_bound(&arr, &2, Ascending), 4);
+        assert_eq!(lower_bound(&arr, &8, Ascending), 5);
+        assert_eq!(upper_bound(&arr, &8, Ascending), 6);
+        assert_eq!(lower_bound(&arr, &9, Ascending), 6);
+    }
+
+    #[test]
+    fn desc_bounds() {
+        let arr = [8, 5, 2, 2, 2, 1];
+        assert_eq!(lower_bound(&arr, &9, Descending), 0);
+        assert_eq!(lower_bound(&arr, &2, Descending), 2);
+        assert_eq!(upper_bound(&arr, &2, Descending), 5);
+        assert_eq!(lower_bound(&arr, &0, Descending), 6);
+        assert_eq!(count_equal(&arr, &2, Descending), 3);
+    }
+
+    #[test]
+    fn matches_linear_scan() {
+        let arr = [0, 0, 1, 3, 3, 3, 4, 7, 7, 9];
+        for val in -1..11 {
+            let lower = arr.iter().position(|e| *e >= val).unwrap_or(arr.len());
+            let upper = arr.iter().position(|e| *e > val).unwrap_or(arr.len());
+            assert_eq!(equal_range(&arr, &val, Ascending), (lower, upper));
+            assert_eq!(insertion_point(&arr, &val, Ascending), upper);
+        }
+    }
+
+    #[test]
+    fn by_key() {
+        let arr = [("a", 1), ("b", 4), ("c", 4), ("d", 9)];
+        assert_eq!(lower_bound_by_key(&arr, &4, |e| e.1, Ascending), 1);
+        assert_eq!(upper_bound_by_key(&arr, &4, |e| e.1, Ascending), 3);
+        assert_eq!(equal_range_by_key(&arr, &5, |e| e.1, Ascending), (3, 3));
+    }
+
+    #[test]
+    fn by_cmp() {
+        let arr = [1.0, 2.5, 2.5, 4.0];
+        assert_eq!(equal_range_by(&arr, |e| 2.5f64.partial_cmp(e).unwrap()), (1, 3));
+        assert_eq!(lower_bound_by(&arr, |e| 3.0f64.partial_cmp(e).unwrap()), 3);
+        assert_eq!(upper_bound_by(&arr, |e| 0.5f64.partial_cmp(e).unwrap()), 0);
+    }
+}
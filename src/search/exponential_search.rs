@@ -0,0 +1,219 @@
+use sort::SortingOrder;
+use super::binary_first_by;
+use super::bounds::partition_point_impl;
+
+#[inline(always)]
+fn exponential_search_impl<T, F>(arr: &[T], mut to_left: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+{
+    if arr.is_empty() {
+        return None;
+    }
+    //double the bound until an element meeting the criteria is found
+    let mut bound = 1usize;
+    while bound < arr.len() && !to_left(&arr[bound]) {
+        bound *= 2;
+    }
+    //the first matching element is in (bound/2, bound]
+    let lo = bound / 2;
+    let hi = arr.len().min(bound + 1);
+    binary_first_by(&arr[lo..hi], to_left).map(|idx| lo + idx)
+}
+
+/**
+Using exponential search finds the first element in the slice that meets the given criteria.
+
+The predicate follows the convention of ```binary_first_by()```: elements meeting the given
+criteria are at the end of the slice. Instead of halving the whole slice, exponential search
+checks elements at indexes 1, 2, 4, 8... and then performs binary search in the last
+range. This is faster than binary search when the searched element is close to the beginning
+of the slice.
+
+**More:** <https://en.wikipedia.org/wiki/Exponential_search>
+
+# Complexity
+
+- Processing complexity: O(log(i))
+- Memory complexity: O(1)
+
+where i - index of the found element.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::exponential_search_by;
+fn main(){
+    let arr: Vec<u32> = (0..1000).collect();
+    //find index of the first element that is greater than 3
+    assert_eq!(exponential_search_by(&arr, |x| *x>3), Some(4));
+    assert_eq!(exponential_search_by(&arr, |x| *x>1000), None);
+}
+```
+*/
+pub fn exponential_search_by<T, F>(arr: &[T], to_left: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+{
+    exponential_search_impl(arr, to_left)
+}
+
+/**
+Performs exponential search for the value in a sorted slice.
+
+Returns index of the first element equal to the searched value.
+Exponential search is faster than binary search when the searched value is close to the
+beginning of the slice.
+
+**More:** <https://en.wikipedia.org/wiki/Exponential_search>
+
+# Complexity
+
+- Processing complexity: O(log(i))
+- Memory complexity: O(1)
+
+where i - index of the found element.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::exponential_search;
+use algorithm::sort::SortingOrder::*;
+
+fn main() {
+    let asc = [0, 3, 7, 7, 11, 13, 22];
+    assert_eq!(exponential_search(&asc, &7, Ascending), Some(2));
+    assert_eq!(exponential_search(&asc, &12, Ascending), None);
+}
+```
+*/
+pub fn exponential_search<T>(arr: &[T], val: &T, order: SortingOrder) -> Option<usize>
+    where
+        T: Ord,
+{
+    let found = match order {
+        SortingOrder::Ascending => exponential_search_impl(arr, |e| e >= val),
+        SortingOrder::Descending => exponential_search_impl(arr, |e| e <= val),
+    };
+    match found {
+        Some(idx) if arr[idx] == *val => Some(idx),
+        _ => None,
+    }
+}
+
+/**
+Finds the first element meeting the given criteria, galloping from the provided position.
+
+The predicate follows the convention of ```binary_first_by()```, but the result is the insertion
+point: ```arr.len()``` is returned if no element meets the criteria. The search checks elements
+at growing distances from ```hint``` in the proper direction and then performs binary search,
+so it is very fast if the result is close to the hint. This is the core of merging in TimSort,
+where consecutive searches often end close to each other.
+
+**More:** <https://en.wikipedia.org/wiki/Timsort#Galloping_mode>
+
+# Complexity
+
+- Processing complexity: O(log(d))
+- Memory complexity: O(1)
+
+where d - distance between the hint and the result.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::gallop_search;
+fn main(){
+    let arr = [1, 3, 5, 7, 9, 11, 13];
+    //position where 8 should be inserted, starting near index 2
+    assert_eq!(gallop_search(&arr, 2, |x| *x>8), 4);
+    assert_eq!(gallop_search(&arr, 6, |x| *x>0), 0);
+    assert_eq!(gallop_search(&arr, 0, |x| *x>20), 7);
+}
+```
+*/
+pub fn gallop_search<T, F>(arr: &[T], hint: usize, mut to_left: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+{
+    let len = arr.len();
+    let hint = hint.min(len);
+    let (lo, hi) = if hint == len || to_left(&arr[hint]) {
+        //the result is at or before the hint (len acts as a sentinel), gallop to the left
+        let mut hi = hint;
+        let mut step = 1usize;
+        loop {
+            if step > hint {
+                break (0, hi);
+            }
+            let idx = hint - step;
+            if !to_left(&arr[idx]) {
+                break (idx + 1, hi);
+            }
+            hi = idx;
+            step *= 2;
+        }
+    } else {
+        //the result is after the hint, gallop to the right
+        let mut lo = hint + 1;
+        let mut step = 1usize;
+        loop {
+            let idx = hint.saturating_add(step);
+            if idx >= len {
+                break (lo, len);
+            }
+            if to_left(&arr[idx]) {
+                break (lo, idx);
+            }
+            lo = idx + 1;
+            step *= 2;
+        }
+    };
+    lo + partition_point_impl(&arr[lo..hi], to_left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::SortingOrder::*;
+
+    #[test]
+    fn exp_empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(exponential_search_by(&arr, |a| *a > 5), None);
+    }
+
+    #[test]
+    fn exp_matches_binary_first() {
+        let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89];
+        for val in -1..91 {
+            assert_eq!(exponential_search_by(&arr, |a| *a >= val), binary_first_by(&arr, |a| *a >= val));
+        }
+    }
+
+    #[test]
+    fn exp_desc() {
+        let arr = [9, 7, 7, 5, 3, 1];
+        assert_eq!(exponential_search(&arr, &7, Descending), Some(1));
+        assert_eq!(exponential_search(&arr, &1, Descending), Some(5));
+        assert_eq!(exponential_search(&arr, &4, Descending), None);
+    }
+
+    #[test]
+    fn gallop_every_hint() {
+        let arr = [0, 2, 2, 4, 6, 6, 6, 8, 10];
+        for val in -1..12 {
+            let expected = arr.iter().position(|a| *a > val).unwrap_or(arr.len());
+            for hint in 0..arr.len() + 3 {
+                assert_eq!(gallop_search(&arr, hint, |a| *a > val), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn gallop_empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(gallop_search(&arr, 0, |a| *a > 5), 0);
+        assert_eq!(gallop_search(&arr, 3, |a| *a > 5), 0);
+    }
+}
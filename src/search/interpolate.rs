@@ -0,0 +1,104 @@
+/**
+Values that can be used as keys of interpolation search.
+
+Interpolation search estimates the position of the searched value assuming that values between
+two known ones are distributed uniformly. This trait provides this estimation. It is implemented
+for all primitive integer and floating-point types and can be implemented for custom key types,
+for example by interpolating one of their fields.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::{Interpolate, interpolation_search};
+use algorithm::sort::SortingOrder::*;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct Timestamp(u64);
+
+impl Interpolate for Timestamp {
+    fn interpolate(&self, from: &Self, to: &Self) -> f64 {
+        self.0.interpolate(&from.0, &to.0)
+    }
+}
+
+fn main() {
+    assert_eq!(15u64.interpolate(&10, &20), 0.5);
+    assert_eq!(15i8.interpolate(&20, &10), 0.5);
+    let arr = [Timestamp(100), Timestamp(250), Timestamp(u64::MAX)];
+    assert_eq!(interpolation_search(&arr, Timestamp(250), Ascending), Some(1));
+}
+```
+*/
+pub trait Interpolate {
+    /**
+    Returns the relative position of ```self``` between ```from``` and ```to```.
+
+    The result should be 0 for ```from```, 1 for ```to``` and proportionally between them
+    for other values. The order of ```from``` and ```to``` is not specified, so descending
+    collections work too. Imprecise results only make the search slower, never incorrect.
+    */
+    fn interpolate(&self, from: &Self, to: &Self) -> f64;
+}
+
+impl<T> Interpolate for &T
+    where
+        T: Interpolate + ?Sized,
+{
+    #[inline(always)]
+    fn interpolate(&self, from: &Self, to: &Self) -> f64 {
+        (**self).interpolate(*from, *to)
+    }
+}
+
+//types that fit into i128, differences are calculated precisely
+macro_rules! impl_interpolate_exact {
+    ($($t:ty),*) => {$(
+        impl Interpolate for $t {
+            #[inline(always)]
+            fn interpolate(&self, from: &Self, to: &Self) -> f64 {
+                let range = *to as i128 - *from as i128;
+                if range == 0 {
+                    return 0.0;
+                }
+                (*self as i128 - *from as i128) as f64 / range as f64
+            }
+        }
+    )*}
+}
+
+//types that do not fit into i128 and floating-point types
+macro_rules! impl_interpolate_float {
+    ($($t:ty),*) => {$(
+        impl Interpolate for $t {
+            #[inline(always)]
+            fn interpolate(&self, from: &Self, to: &Self) -> f64 {
+                let (val, from, to) = (*self as f64, *from as f64, *to as f64);
+                if to == from {
+                    return 0.0;
+                }
+                (val - from) / (to - from)
+            }
+        }
+    )*}
+}
+
+impl_interpolate_exact!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_interpolate_float!(u128, i128, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extremes() {
+        assert_eq!(u64::MAX.interpolate(&0, &u64::MAX), 1.0);
+        assert_eq!(0i64.interpolate(&i64::MIN, &i64::MAX), 0.5);
+        assert_eq!(0u128.interpolate(&0, &u128::MAX), 0.0);
+    }
+
+    #[test]
+    fn equal_bounds() {
+        assert_eq!(5u8.interpolate(&5, &5), 0.0);
+        assert_eq!(5.0f32.interpolate(&5.0, &5.0), 0.0);
+    }
+}
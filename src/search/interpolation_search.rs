@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use sort::SortingOrder;
+use super::Interpolate;
 
 /**
 Quickly finds an element in an ascending slice.
@@ -7,6 +8,7 @@ Quickly finds an element in an ascending slice.
 Interpolation search is similar to binary search but for collections with uniform
 distribution has a better complexity. If the collection does not have uniform distribution,
 interpolation search is slower than binary search and has O(n) complexity in the worst case.
+```interpolation_binary_search()``` avoids this problem.
 
 **More:** <https://en.wikipedia.org/wiki/Interpolation_search>
 
@@ -29,13 +31,12 @@ fn main(){
 }
 ```
 */
-pub fn interpolation_search<'a, T>(arr: &'a [T], val: T, order: SortingOrder) -> Option<usize>
-    where T: Ord + Clone,
-          f64: From<T>
+pub fn interpolation_search<T>(arr: &[T], val: T, order: SortingOrder) -> Option<usize>
+    where T: Ord + Interpolate
 {
     match order{
-        SortingOrder::Ascending => interpolation_search_impl(arr, val, |ref a, ref b| a.cmp(&b)),
-        SortingOrder::Descending => interpolation_search_impl(arr, val, |ref a, ref b| b.cmp(&a))
+        SortingOrder::Ascending => interpolation_search_impl(arr, &&val, |e| e, |a, b| a.cmp(b), false),
+        SortingOrder::Descending => interpolation_search_impl(arr, &&val, |e| e, |a, b| b.cmp(a), false)
     }
 }
 
@@ -70,47 +71,162 @@ fn main(){
 }
 ```
 */
-pub fn interpolation_search_by<'a, T, F>(arr: &'a [T], val: T, cmp: F) -> Option<usize>
+pub fn interpolation_search_by<T, F>(arr: &[T], val: T, mut cmp: F) -> Option<usize>
     where
-        T: Clone,
-        f64: From<T>,
+        T: Interpolate,
         F: FnMut(&T, &T) -> Ordering
 {
-    interpolation_search_impl(arr, val, cmp)
+    interpolation_search_impl(arr, &&val, |e| e, |a, b| cmp(a, b), false)
 }
 
-#[inline(always)]
-fn interpolation_search_impl<'a, T, F>(arr: &'a [T], val: T, mut cmp: F) -> Option<usize>
-where
-    T: Clone,
-     f64: From<T>,
-    F: FnMut(&T, &T) -> Ordering
+/**
+Quickly finds an element in a sorted slice, comparing keys extracted from elements.
+
+**More:** <https://en.wikipedia.org/wiki/Interpolation_search>
+
+# Complexity
+
+- Average processing complexity (for uniform distribution): O(log(log(n)))
+- Worst case processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::interpolation_search_by_key;
+use algorithm::sort::SortingOrder::*;
+
+fn main(){
+    let arr = [(40u64, 'a'), (30, 'b'), (20, 'c'), (10, 'd')];
+    assert_eq!(interpolation_search_by_key(&arr, &20, |e| e.0, Descending), Some(2));
+    assert_eq!(interpolation_search_by_key(&arr, &25, |e| e.0, Descending), None);
+}
+```
+*/
+pub fn interpolation_search_by_key<T, K, F>(arr: &[T], key: &K, f: F, order: SortingOrder) -> Option<usize>
+    where
+        K: Ord + Interpolate,
+        F: FnMut(&T) -> K,
+{
+    match order {
+        SortingOrder::Ascending => interpolation_search_impl(arr, key, f, |a, b| a.cmp(b), false),
+        SortingOrder::Descending => interpolation_search_impl(arr, key, f, |a, b| b.cmp(a), false),
+    }
+}
+
+/**
+Finds an element in a sorted slice using interpolation search with guaranteed O(log(n)) worst case.
+
+Every interpolation step is followed by a binary search step, so the searched range is
+at least halved in every iteration. On uniformly distributed data this is almost as fast as
+interpolation search and on skewed data it is never much slower than binary search.
+
+**More:** <https://en.wikipedia.org/wiki/Interpolation_search>
+
+# Complexity
+
+- Average processing complexity (for uniform distribution): O(log(log(n)))
+- Worst case processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::interpolation_binary_search;
+use algorithm::sort::SortingOrder::*;
+
+fn main(){
+    //exponential growth is the worst case for the classic interpolation search
+    let arr: Vec<u64> = (0..64).map(|i| 1u64 << i).collect();
+    assert_eq!(interpolation_binary_search(&arr, 1 << 40, Ascending), Some(40));
+    assert_eq!(interpolation_binary_search(&arr, 3, Ascending), None);
+}
+```
+*/
+pub fn interpolation_binary_search<T>(arr: &[T], val: T, order: SortingOrder) -> Option<usize>
+    where T: Ord + Interpolate
+{
+    match order {
+        SortingOrder::Ascending => interpolation_search_impl(arr, &&val, |e| e, |a, b| a.cmp(b), true),
+        SortingOrder::Descending => interpolation_search_impl(arr, &&val, |e| e, |a, b| b.cmp(a), true),
+    }
+}
+
+/**
+Finds an element in a sorted slice using interpolation search with guaranteed O(log(n)) worst case,
+comparing keys extracted from elements.
+
+**More:** <https://en.wikipedia.org/wiki/Interpolation_search>
+
+# Complexity
+
+- Average processing complexity (for uniform distribution): O(log(log(n)))
+- Worst case processing complexity: O(log(n))
+- Memory complexity: O(1)
 
+# Example
+```
+extern crate algorithm;
+use algorithm::search::interpolation_binary_search_by_key;
+use algorithm::sort::SortingOrder::*;
+
+fn main(){
+    let arr = [(1i64, 'a'), (2, 'b'), (1000, 'c'), (1000000, 'd')];
+    assert_eq!(interpolation_binary_search_by_key(&arr, &1000, |e| e.0, Ascending), Some(2));
+}
+```
+*/
+pub fn interpolation_binary_search_by_key<T, K, F>(arr: &[T], key: &K, f: F, order: SortingOrder) -> Option<usize>
+    where
+        K: Ord + Interpolate,
+        F: FnMut(&T) -> K,
 {
-    if arr.is_empty(){
-        return None;
+    match order {
+        SortingOrder::Ascending => interpolation_search_impl(arr, key, f, |a, b| a.cmp(b), true),
+        SortingOrder::Descending => interpolation_search_impl(arr, key, f, |a, b| b.cmp(a), true),
     }
+}
+
+#[inline(always)]
+fn interpolation_search_impl<'a, T, K, G, F>(arr: &'a [T], val: &K, mut key: G, mut cmp: F, with_binary_step: bool) -> Option<usize>
+where
+    K: Interpolate,
+    G: FnMut(&'a T) -> K,
+    F: FnMut(&K, &K) -> Ordering
+{
+    //the searched range is [lo, hi)
     let mut lo = 0usize;
-    let mut hi = arr.len() -1;
-    let val_f64 = f64::from(val.clone());
-    while lo <= hi && cmp(&val, &arr[lo]) != Ordering::Less && cmp(&val, &arr[hi]) != Ordering::Greater
-    {
+    let mut hi = arr.len();
+    let probes = if with_binary_step { 2 } else { 1 };
+    while lo < hi {
+        let first = key(&arr[lo]);
+        let last = key(&arr[hi - 1]);
+        if cmp(val, &first) == Ordering::Less || cmp(val, &last) == Ordering::Greater {
+            return None;
+        }
         // Probing the position with keeping
         // uniform distribution in mind.
-        let y_lo = f64::from(arr[lo].clone());
-        let x_diff = (hi-lo) as f64;
-        let y_diff = f64::from(arr[hi].clone()) - y_lo;
-        let val_diff: f64 = val_f64 - y_lo;
-
-        let mid =  lo + ((x_diff *(val_diff/y_diff)) as usize);
-
-        match cmp(&val, &arr[mid]) {
-            Ordering::Less => hi = mid -1,
-            Ordering::Greater => lo = mid+1,
-            Ordering::Equal => return Option::Some(mid)
+        let fraction = val.interpolate(&first, &last);
+        //invalid fractions (for example NaN) are replaced with the first element
+        let fraction = if fraction > 0.0 { fraction.min(1.0) } else { 0.0 };
+        for probe in 0..probes {
+            if lo >= hi {
+                break;
+            }
+            let mid = if probe == 0 {
+                (lo + (((hi - 1 - lo) as f64) * fraction) as usize).min(hi - 1)
+            } else {
+                //binary search step guarantees that the range is at least halved
+                lo + (hi - lo) / 2
+            };
+            match cmp(val, &key(&arr[mid])) {
+                Ordering::Less => hi = mid,
+                Ordering::Greater => lo = mid + 1,
+                Ordering::Equal => return Some(mid)
+            }
         }
     }
-    Option::None
+    None
 }
 
 #[cfg(test)]
@@ -152,4 +268,45 @@ mod tests {
         let arr = [5, 7, 8, 12, 22, 33];
         assert_eq!(interpolation_search(&arr, 13, Ascending), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn desc_all() {
+        let arr = [90u64, 70, 70, 40, 10, 5];
+        for val in 0..100 {
+            let found = interpolation_search(&arr, val, Descending);
+            assert_eq!(found.is_some(), arr.contains(&val));
+            assert_eq!(found.map(|idx| arr[idx]).unwrap_or(val), val);
+            let hybrid = interpolation_binary_search(&arr, val, Descending);
+            assert_eq!(hybrid.map(|idx| arr[idx]), found.map(|idx| arr[idx]));
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+        let arr = [3usize; 10];
+        assert_eq!(interpolation_search(&arr, 3, Ascending).map(|idx| arr[idx]), Some(3));
+        assert_eq!(interpolation_binary_search(&arr, 4, Ascending), None);
+    }
+
+    #[test]
+    fn hybrid_is_logarithmic() {
+        let arr: Vec<u64> = (0..64).map(|i| 1u64 << i).collect();
+        let probes = |hybrid: bool| {
+            let mut probes = 0;
+            let found = interpolation_search_impl(&arr, &(1u64 << 62), |e| { probes += 1; *e }, |a, b| a.cmp(b), hybrid);
+            assert_eq!(found, Some(62));
+            probes
+        };
+        assert!(probes(true) < probes(false));
+        assert!(probes(true) <= 4 * 7);
+    }
+
+    #[test]
+    fn hybrid_matches_binary_search() {
+        let arr: Vec<i64> = (0..200).map(|i| i * i * i - 1000).collect();
+        let values = arr.iter().cloned().chain((-1100..8_000_000i64).step_by(997));
+        for val in values {
+            assert_eq!(interpolation_binary_search(&arr, val, Ascending), arr.binary_search(&val).ok());
+        }
+    }
+}
@@ -5,10 +5,26 @@ Searching algorithms.
 
 mod binary_search;
 mod quick_select;
+mod interpolate;
 mod interpolation_search;
 mod binary_first;
+mod bounds;
+mod exponential_search;
+mod monotone;
+mod bisect;
+mod parallel_binary_search;
+mod ternary_search;
+mod static_search_table;
 
 pub use self::binary_search::{binary_search, binary_search_by};
 pub use self::binary_first::{binary_first_by};
-pub use self::interpolation_search::{interpolation_search, interpolation_search_by};
-pub use self::quick_select::{quick_select, quick_select_rand, quick_select_by, quick_select_rand_by};
+pub use self::bounds::{lower_bound, lower_bound_by, lower_bound_by_key, upper_bound, upper_bound_by, upper_bound_by_key, equal_range, equal_range_by, equal_range_by_key, count_equal, insertion_point};
+pub use self::exponential_search::{exponential_search, exponential_search_by, gallop_search};
+pub use self::monotone::{search_monotone, search_monotone_from};
+pub use self::bisect::{first_true_in_range, bisect, BisectLimit};
+pub use self::parallel_binary_search::parallel_binary_search;
+pub use self::ternary_search::{ternary_search_int, ternary_search_slice, golden_section_search, Extremum};
+pub use self::interpolate::Interpolate;
+pub use self::static_search_table::{StaticSearchTable, SearchLayout};
+pub use self::interpolation_search::{interpolation_search, interpolation_search_by, interpolation_search_by_key, interpolation_binary_search, interpolation_binary_search_by_key};
+pub use self::quick_select::{quick_select, quick_select_rand, quick_select_by, quick_select_rand_by, quick_select_rand_with_rng, quick_select_rand_by_with_rng};
@@ -0,0 +1,137 @@
+use utils::Integer;
+
+/**
+Finds the smallest non-negative integer for which the monotone predicate is true.
+
+The predicate must be false for all values below some threshold and true for all values
+starting from it - this function finds the threshold without knowing any upper bound.
+It checks 0, 1, 2, 4, 8, 16... (doubling the step) and then performs binary search in the last range.
+Returns None if the predicate is false for the greatest value of the type.
+This is the same as ```search_monotone_from(T::zero(), pred)```.
+
+**More:** <https://en.wikipedia.org/wiki/Exponential_search>
+
+# Complexity
+
+- Processing complexity: O(log(r))
+- Memory complexity: O(1)
+
+where r - the result.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::search_monotone;
+fn main(){
+    //the smallest number whose square exceeds 1000
+    assert_eq!(search_monotone(|x: u64| x*x > 1000), Some(32));
+    assert_eq!(search_monotone(|_: u8| false), None);
+}
+```
+*/
+pub fn search_monotone<T, F>(pred: F) -> Option<T>
+    where
+        T: Integer,
+        F: FnMut(T) -> bool,
+{
+    search_monotone_from(T::zero(), pred)
+}
+
+/**
+Finds the smallest integer not lower than ```start``` for which the monotone predicate is true.
+
+The predicate must be false for all values below some threshold and true for all values
+starting from it. Values are checked with exponentially growing distance from ```start```
+and then binary search is performed in the last range. Returns None if the predicate is false
+for the greatest value of the type. Overflows are handled, so the whole range of the type
+can be searched.
+
+**More:** <https://en.wikipedia.org/wiki/Exponential_search>
+
+# Complexity
+
+- Processing complexity: O(log(r - start))
+- Memory complexity: O(1)
+
+where r - the result.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::search_monotone_from;
+fn main(){
+    assert_eq!(search_monotone_from(-100i32, |x| x >= -42), Some(-42));
+    assert_eq!(search_monotone_from(10i32, |x| x >= -42), Some(10));
+    assert_eq!(search_monotone_from(0i8, |x| x == i8::MAX), Some(i8::MAX));
+}
+```
+*/
+pub fn search_monotone_from<T, F>(start: T, mut pred: F) -> Option<T>
+    where
+        T: Integer,
+        F: FnMut(T) -> bool,
+{
+    if pred(start) {
+        return Some(start);
+    }
+    //invariant: pred(lo) is false, pred(hi) is true
+    let mut lo = start;
+    //None means that the step overflowed and the greatest value should be checked
+    let mut step = Some(T::one());
+    let mut hi = loop {
+        let next = match step.and_then(|s| start.checked_add(s)) {
+            Some(next) => next,
+            None => T::max_value(),
+        };
+        if pred(next) {
+            break next;
+        }
+        if next == T::max_value() {
+            return None;
+        }
+        lo = next;
+        step = step.and_then(|s| s.checked_add(s));
+    };
+    while lo.checked_add(T::one()) != Some(hi) {
+        let mid = T::midpoint(lo, hi);
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(hi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_threshold() {
+        for threshold in 0..300u32 {
+            assert_eq!(search_monotone(|x: u32| x >= threshold), Some(threshold));
+        }
+    }
+
+    #[test]
+    fn whole_range() {
+        assert_eq!(search_monotone(|x: u64| x == u64::MAX), Some(u64::MAX));
+        assert_eq!(search_monotone(|x: u64| x > u64::MAX - 5), Some(u64::MAX - 4));
+        assert_eq!(search_monotone_from(i64::MIN, |x| x >= 0), Some(0));
+        assert_eq!(search_monotone_from(i8::MIN, |x| x == i8::MAX), Some(i8::MAX));
+    }
+
+    #[test]
+    fn not_found() {
+        assert_eq!(search_monotone(|_: i16| false), None);
+        assert_eq!(search_monotone_from(u8::MAX, |_| false), None);
+    }
+
+    #[test]
+    fn counts_calls() {
+        let mut calls = 0;
+        assert_eq!(search_monotone(|x: u64| { calls += 1; x >= 1000 }), Some(1000));
+        assert!(calls <= 25);
+    }
+}
@@ -0,0 +1,122 @@
+/**
+Answers many binary search queries over one monotone process at the same time.
+
+The process starts from a state created by ```init``` and consists of ```steps``` steps applied
+in order by ```apply```. For every query this function finds the smallest number of applied steps
+after which ```check(state, query)``` is true. The check must flip only once for every query:
+once it becomes true, applying more steps must not make it false (the same convention as in
+```binary_first_by()```). Zero is returned if the check is true for the initial state and None
+if it is false even after applying all steps.
+
+Instead of running a separate binary search (and replaying the process) for every query, all
+queries are moved forward together: in each round the process is replayed once and every query
+is checked at the middle of its current range.
+
+**More:** <https://cp-algorithms.com/num_methods/binary_search.html#parallel-binary-search>
+
+# Complexity
+
+- Processing complexity: O((s + q) * log(s)) calls to ```apply``` and ```check```
+- Memory complexity: O(q)
+
+where s - number of steps, q - number of queries.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::parallel_binary_search;
+use algorithm::collections::DisjointSet;
+
+fn main(){
+    //edges added to a graph one by one
+    let edges = [(1, 2), (3, 4), (2, 3), (5, 6), (4, 5)];
+    //when do these pairs of vertexes become connected?
+    let queries = [(1, 2), (1, 4), (1, 6), (1, 7), (3, 3)];
+    let answers = parallel_binary_search(
+        queries.len(),
+        edges.len(),
+        || DisjointSet::<i32>::new(),
+        |ds, step| ds.union(edges[step].0, edges[step].1),
+        |ds, query| queries[query].0 == queries[query].1 || ds.in_union(&queries[query].0, &queries[query].1)
+    );
+    assert_eq!(answers, vec![Some(1), Some(3), Some(5), None, Some(0)]);
+}
+```
+*/
+pub fn parallel_binary_search<S, I, A, C>(queries: usize, steps: usize, mut init: I, mut apply: A, mut check: C) -> Vec<Option<usize>>
+    where
+        I: FnMut() -> S,
+        A: FnMut(&mut S, usize),
+        C: FnMut(&mut S, usize) -> bool,
+{
+    //the answer of every query is in [lo, hi], steps+1 means no answer
+    let mut lo = vec![0usize; queries];
+    let mut hi = vec![steps + 1; queries];
+    let mut pending: Vec<(usize, usize)> = Vec::with_capacity(queries);
+    loop {
+        pending.clear();
+        pending.extend((0..queries)
+            .filter(|&q| lo[q] < hi[q])
+            .map(|q| (lo[q] + (hi[q] - lo[q]) / 2, q)));
+        if pending.is_empty() {
+            break;
+        }
+        pending.sort_unstable();
+        //replay the process once, checking queries in the order of their middle points
+        let mut state = init();
+        let mut applied = 0;
+        for &(mid, q) in &pending {
+            while applied < mid {
+                apply(&mut state, applied);
+                applied += 1;
+            }
+            if check(&mut state, q) {
+                hi[q] = mid;
+            } else {
+                lo[q] = mid + 1;
+            }
+        }
+    }
+    lo.into_iter().map(|l| if l > steps { None } else { Some(l) }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_queries() {
+        let res = parallel_binary_search(0, 10, || (), |_, _| (), |_, _| true);
+        assert!(res.is_empty());
+    }
+
+    #[test]
+    fn no_steps() {
+        let res = parallel_binary_search(2, 0, || 0, |_, _| (), |_, q| q == 1);
+        assert_eq!(res, vec![None, Some(0)]);
+    }
+
+    #[test]
+    fn thresholds() {
+        //state is the running sum, query q asks when the sum reaches q
+        let values = [3, 1, 4, 1, 5, 9, 2, 6];
+        let res = parallel_binary_search(40, values.len(), || 0, |sum, step| *sum += values[step], |sum, q| *sum >= q);
+        for (q, answer) in res.into_iter().enumerate() {
+            let mut sum = 0;
+            let expected = (0..values.len() + 1).find(|&n| {
+                if n > 0 {
+                    sum += values[n - 1];
+                }
+                sum >= q
+            });
+            assert_eq!(answer, expected);
+        }
+    }
+
+    #[test]
+    fn replays_are_logarithmic() {
+        let mut replays = 0;
+        parallel_binary_search(100, 1000, || replays += 1, |_, _| (), |_, _| false);
+        assert!(replays <= 11);
+    }
+}
@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use utils::{partition, partition_rand};
+use rand::{Rng, thread_rng};
 
 /**
 Finds n-th element in an unsorted slice using a custom comparator.
@@ -77,7 +78,7 @@ pub fn quick_select_rand_by<T, F>(arr: &mut [T], n: usize, is_ordered: F) -> T
         T: Clone,
         F: FnMut(&T, &T) -> bool+Copy,
 {
-    quick_select_impl(arr, n, is_ordered, partition_rand)
+    quick_select_rand_by_with_rng(arr, n, is_ordered, &mut thread_rng())
 }
 
 /**
@@ -153,14 +154,94 @@ pub fn quick_select_rand<T>(arr: &mut [T], n: usize) -> T
 where
     T: Clone + Ord,
 {
-    quick_select_impl(arr, n, |a, b| a<b, partition_rand)
+    quick_select_rand_by_with_rng(arr, n, |a, b| a<b, &mut thread_rng())
+}
+
+/**
+Finds n-th element in an unsorted slice using a custom comparator and the provided random number generator.
+
+This is the same algorithm as ```quick_select_rand_by()```, but pivots are chosen using the given
+generator, so a seeded generator makes the process reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Quickselect>
+
+# Complexity
+
+- Average processing complexity: O(n)
+- Worst case processing complexity: O(n²)
+- Memory complexity: O(1)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::search::quick_select_rand_by_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+let mut arr = ['d', 'a', 'b', 'h', 'c', 'f', 'e', 'g'];
+let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+//find the fifth element in the array after sorting in the descending order
+let fifth = quick_select_rand_by_with_rng(&mut arr, 4, |a, b| a>b, &mut rng);
+assert_eq!(fifth, 'd');
+}
+```
+*/
+pub fn quick_select_rand_by_with_rng<T, F, R>(arr: &mut [T], n: usize, is_ordered: F, rng: &mut R) -> T
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> bool+Copy,
+        R: Rng
+{
+    quick_select_impl(arr, n, is_ordered, |a, f| partition_rand(a, f, rng))
+}
+
+/**
+Finds n-th element in an unsorted slice using the provided random number generator.
+
+This is the same algorithm as ```quick_select_rand()```, but pivots are chosen using the given
+generator, so a seeded generator makes the process reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Quickselect>
+
+# Complexity
+
+- Average processing complexity: O(n)
+- Worst case processing complexity: O(n²)
+- Memory complexity: O(1)
+
+# Example
+
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::search::quick_select_rand_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+
+fn main(){
+let mut arr = ['d', 'a', 'b', 'h', 'c', 'f', 'e', 'g'];
+let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+//find the fifth element in the array after sorting
+let fifth = quick_select_rand_with_rng(&mut arr, 4, &mut rng);
+assert_eq!(fifth, 'e');
+}
+```
+*/
+pub fn quick_select_rand_with_rng<T, R>(arr: &mut [T], n: usize, rng: &mut R) -> T
+    where
+        T: Clone + Ord,
+        R: Rng
+{
+    quick_select_rand_by_with_rng(arr, n, |a, b| a<b, rng)
 }
 
 #[inline(always)]
-fn quick_select_impl<T, F>(arr: &mut [T], mut n: usize, is_ordered: F, partition: fn(&mut [T], F) -> usize) -> T
+fn quick_select_impl<T, F, P>(arr: &mut [T], mut n: usize, is_ordered: F, mut partition: P) -> T
 where
     T: Clone,
     F: FnMut(&T, &T) -> bool+Copy,
+    P: FnMut(&mut [T], F) -> usize,
 {
     if n >= arr.len() {
         panic!("n is outside of the array.");
@@ -248,4 +329,14 @@ mod tests {
         assert_eq!(quick_select(&mut arr, 4), 5);
     }
 
+    #[test]
+    fn qs_seeded() {
+        use rand::{SeedableRng, XorShiftRng};
+        let mut rng = XorShiftRng::from_seed([4, 3, 2, 1]);
+        for n in 0..10 {
+            let mut arr = [3, 6, 5, 0, 8, 9, 2, 1, 4, 7];
+            assert_eq!(quick_select_rand_with_rng(&mut arr, n, &mut rng), n as i32);
+        }
+    }
+
 }
@@ -0,0 +1,366 @@
+use sort::{apply_permutation, invert_permutation};
+use std::iter::FromIterator;
+
+//number of keys in one node of the B-tree layout, 16 four-byte keys fill one cache line
+const BLOCK: usize = 16;
+
+/**
+Memory layout of ```StaticSearchTable```.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SearchLayout {
+    ///Eytzinger (BFS) layout of a binary tree: children of the node k are 2k and 2k+1.
+    Eytzinger,
+    ///Static B-tree (S-tree) layout: nodes of 16 keys stored in BFS order.
+    BTree,
+}
+
+/**
+Sorted, read-only set of values with a cache-friendly memory layout.
+
+Binary search in a sorted slice accesses memory in a very cache-unfriendly way: the first
+few levels are spread across the whole slice and every step is a cache miss.
+This table rearranges the values so that elements checked one after another are close
+to each other, and prefetches the following levels in advance. This gives a significant speedup
+for big, read-heavy lookup tables. Results of ```lower_bound()``` are the same as on the sorted values.
+```binary_search()``` always returns the position of the first equal value, while binary search in a slice
+can return any of them if there are duplicates. Because of that the table is a drop-in replacement
+for ```binary_search()``` only for values without duplicates.
+
+**More:** <https://en.algorithmica.org/hpc/data-structures/binary-search/>
+
+# Complexity
+
+- Creation complexity: O(n*log(n)), O(n) if values are already sorted
+- Search complexity: O(log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::{StaticSearchTable, SearchLayout};
+
+fn main(){
+    let table = StaticSearchTable::new(vec![13, 2, 8, 5, 21, 3]);
+    //positions are the same as in the sorted slice [2, 3, 5, 8, 13, 21]
+    assert_eq!(table.lower_bound(&6), 3);
+    assert_eq!(table.binary_search(&13), Some(4));
+    assert!(table.contains(&21));
+    assert!(!table.contains(&4));
+
+    let table = StaticSearchTable::with_layout(table.into_sorted(), SearchLayout::BTree);
+    assert_eq!(table.lower_bound(&100), 6);
+    assert_eq!(table.into_sorted(), vec![2, 3, 5, 8, 13, 21]);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct StaticSearchTable<T> {
+    //values in the layout order, the B-tree layout is padded with copies of the greatest value
+    data: Vec<T>,
+    //position of every value in the sorted order, values >= len mark padding
+    ranks: Vec<usize>,
+    len: usize,
+    layout: SearchLayout,
+}
+
+impl<T> StaticSearchTable<T> where T: Ord {
+    /**
+    Creates a new table with the Eytzinger layout. Values do not need to be sorted.
+    */
+    pub fn new(mut values: Vec<T>) -> Self {
+        values.sort();
+        let ranks = eytzinger_ranks(values.len());
+        Self::from_sorted(values, ranks, SearchLayout::Eytzinger)
+    }
+
+    /**
+    Creates a new table with the given layout. Values do not need to be sorted.
+    */
+    pub fn with_layout(mut values: Vec<T>, layout: SearchLayout) -> Self where T: Clone {
+        match layout {
+            SearchLayout::Eytzinger => Self::new(values),
+            SearchLayout::BTree => {
+                values.sort();
+                let len = values.len();
+                let ranks = btree_ranks(len);
+                if let Some(max) = values.last().cloned() {
+                    values.resize(ranks.len(), max);
+                }
+                let mut table = Self::from_sorted(values, ranks, layout);
+                table.len = len;
+                table
+            }
+        }
+    }
+
+    fn from_sorted(mut values: Vec<T>, ranks: Vec<usize>, layout: SearchLayout) -> Self {
+        apply_permutation(&mut values, &ranks);
+        Self {
+            len: values.len(),
+            data: values,
+            ranks,
+            layout,
+        }
+    }
+
+    /**
+    Returns the number of values.
+    */
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /**
+    Checks if the table is empty.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /**
+    Returns the layout of the table.
+    */
+    pub fn layout(&self) -> SearchLayout {
+        self.layout
+    }
+
+    /**
+    Finds the position of the first value not less than the searched one, in the sorted order.
+
+    Returns ```len()``` if all values are less than the searched one.
+
+    **Complexity:** O(log(n))
+    */
+    pub fn lower_bound(&self, val: &T) -> usize {
+        match self.lower_bound_slot(val) {
+            Some(slot) => self.ranks[slot],
+            None => self.len,
+        }
+    }
+
+    /**
+    Returns the first value not less than the searched one.
+
+    **Complexity:** O(log(n))
+    */
+    pub fn lower_bound_value(&self, val: &T) -> Option<&T> {
+        self.lower_bound_slot(val).map(|slot| &self.data[slot])
+    }
+
+    /**
+    Finds the position of the value in the sorted order.
+
+    If there are several equal values, the position of the first one is returned,
+    so the result equals ```lower_bound()``` whenever the value is present.
+
+    **Complexity:** O(log(n))
+    */
+    pub fn binary_search(&self, val: &T) -> Option<usize> {
+        match self.lower_bound_slot(val) {
+            Some(slot) if self.data[slot] == *val => Some(self.ranks[slot]),
+            _ => None,
+        }
+    }
+
+    /**
+    Checks if the table contains the value.
+
+    **Complexity:** O(log(n))
+    */
+    pub fn contains(&self, val: &T) -> bool {
+        self.lower_bound_value(val) == Some(val)
+    }
+
+    /**
+    Converts the table back into a sorted vector.
+
+    **Complexity:** O(n)
+    */
+    pub fn into_sorted(self) -> Vec<T> {
+        let mut data = self.data;
+        apply_permutation(&mut data, &invert_permutation(&self.ranks));
+        data.truncate(self.len);
+        data
+    }
+
+    //finds the slot with the first value (in the sorted order) not less than val
+    fn lower_bound_slot(&self, val: &T) -> Option<usize> {
+        match self.layout {
+            SearchLayout::Eytzinger => self.lower_bound_eytzinger(val),
+            SearchLayout::BTree => self.lower_bound_btree(val),
+        }
+    }
+
+    fn lower_bound_eytzinger(&self, val: &T) -> Option<usize> {
+        let data = &self.data;
+        let len = data.len();
+        //1-based index of the current node
+        let mut k = 1usize;
+        while k <= len {
+            //descendants 4 levels deeper are stored next to each other
+            prefetch(data.as_ptr().wrapping_add((k << 4).wrapping_sub(1)));
+            //this is safe, k is in [1, len]
+            let go_right = unsafe { data.get_unchecked(k - 1) } < val;
+            k = 2 * k + go_right as usize;
+        }
+        //the answer is the last node where the search went left
+        k >>= (!k).trailing_zeros() + 1;
+        if k == 0 {
+            None
+        } else {
+            Some(k - 1)
+        }
+    }
+
+    fn lower_bound_btree(&self, val: &T) -> Option<usize> {
+        let data = &self.data;
+        let blocks = data.len() / BLOCK;
+        let mut result = None;
+        let mut k = 0usize;
+        while k < blocks {
+            let block = &data[k * BLOCK..(k + 1) * BLOCK];
+            //counting without branching is faster than searching in such a small block
+            let idx = block.iter().filter(|e| *e < val).count();
+            let child = k * (BLOCK + 1) + idx + 1;
+            prefetch(data.as_ptr().wrapping_add(child * BLOCK));
+            if idx < BLOCK {
+                result = Some(k * BLOCK + idx);
+            }
+            k = child;
+        }
+        result
+    }
+}
+
+impl<T> StaticSearchTable<T> where T: Ord + Clone {
+    /**
+    Returns the values in the sorted order.
+
+    **Complexity:** O(n)
+    */
+    pub fn to_sorted(&self) -> Vec<T> {
+        self.clone().into_sorted()
+    }
+}
+
+impl<T> FromIterator<T> for StaticSearchTable<T> where T: Ord {
+    ///Creates a new table with the Eytzinger layout.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+//hints the processor to load the memory into the cache, invalid addresses are ignored
+#[inline(always)]
+fn prefetch<T>(ptr: *const T) {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        _mm_prefetch::<_MM_HINT_T0>(ptr as *const i8);
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = ptr;
+}
+
+//positions in the sorted order of values stored in the Eytzinger layout
+fn eytzinger_ranks(len: usize) -> Vec<usize> {
+    fn visit(ranks: &mut Vec<usize>, next: &mut usize, k: usize) {
+        if k <= ranks.len() {
+            visit(ranks, next, 2 * k);
+            ranks[k - 1] = *next;
+            *next += 1;
+            visit(ranks, next, 2 * k + 1);
+        }
+    }
+    let mut ranks = vec![0; len];
+    visit(&mut ranks, &mut 0, 1);
+    ranks
+}
+
+//positions in the sorted order of values stored in the B-tree layout, including padding
+fn btree_ranks(len: usize) -> Vec<usize> {
+    fn visit(ranks: &mut Vec<usize>, next: &mut usize, k: usize) {
+        if k * BLOCK < ranks.len() {
+            for i in 0..BLOCK {
+                visit(ranks, next, k * (BLOCK + 1) + i + 1);
+                ranks[k * BLOCK + i] = *next;
+                *next += 1;
+            }
+            visit(ranks, next, k * (BLOCK + 1) + BLOCK + 1);
+        }
+    }
+    let blocks = len.div_ceil(BLOCK);
+    let mut ranks = vec![0; blocks * BLOCK];
+    visit(&mut ranks, &mut 0, 0);
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use search::{lower_bound, binary_search};
+    use sort::SortingOrder::*;
+
+    fn check(values: Vec<i32>, layout: SearchLayout) {
+        let mut sorted = values.clone();
+        sorted.sort();
+        let table = StaticSearchTable::with_layout(values, layout);
+        assert_eq!(table.len(), sorted.len());
+        let max = sorted.last().cloned().unwrap_or(0);
+        for val in -2..max + 3 {
+            assert_eq!(table.lower_bound(&val), lower_bound(&sorted, &val, Ascending));
+            assert_eq!(table.contains(&val), sorted.contains(&val));
+            assert_eq!(table.binary_search(&val).map(|idx| sorted[idx]), binary_search(&sorted, &val, Ascending).map(|idx| sorted[idx]));
+            //the first equal value is returned
+            if table.contains(&val) {
+                assert_eq!(table.binary_search(&val), Some(lower_bound(&sorted, &val, Ascending)));
+            }
+        }
+        assert_eq!(table.to_sorted(), sorted);
+    }
+
+    #[test]
+    fn eytzinger_sizes() {
+        for len in 0..70 {
+            check((0..len).map(|i| (i * 7919) % 101).collect(), SearchLayout::Eytzinger);
+        }
+    }
+
+    #[test]
+    fn btree_sizes() {
+        for len in (0..100).chain(250..300) {
+            check((0..len).map(|i| (i * 7919) % 1009).collect(), SearchLayout::BTree);
+        }
+    }
+
+    #[test]
+    fn unique_positions() {
+        let sorted: Vec<i32> = (0..1000).map(|i| i * 3).collect();
+        for &layout in &[SearchLayout::Eytzinger, SearchLayout::BTree] {
+            let table = StaticSearchTable::with_layout(sorted.clone(), layout);
+            for val in -1..3001 {
+                assert_eq!(table.binary_search(&val), binary_search(&sorted, &val, Ascending));
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+        check(vec![5; 40], SearchLayout::BTree);
+        check(vec![1, 1, 2, 2, 2, 3], SearchLayout::Eytzinger);
+        let table: StaticSearchTable<i32> = vec![4, 4, 4, 1].into_iter().collect();
+        assert_eq!(table.binary_search(&4), Some(1));
+        let table = StaticSearchTable::with_layout(vec![7; 40], SearchLayout::BTree);
+        assert_eq!(table.binary_search(&7), Some(0));
+    }
+
+    #[test]
+    fn not_clone() {
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+        struct Key(u32);
+        let table = StaticSearchTable::new(vec![Key(3), Key(1), Key(2)]);
+        assert_eq!(table.lower_bound_value(&Key(2)), Some(&Key(2)));
+        assert_eq!(table.into_sorted(), vec![Key(1), Key(2), Key(3)]);
+    }
+}
@@ -0,0 +1,250 @@
+use std::ops::Range;
+use utils::Integer;
+use super::bisect::{first_true_in_range, validate_limit, BisectLimit};
+
+/**
+Represents the kind of extremum searched in a unimodal function.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Extremum {
+    ///Minimum, the function first decreases and then increases.
+    Minimum,
+    ///Maximum, the function first increases and then decreases.
+    Maximum,
+}
+
+impl Extremum {
+    //checks if a is strictly better than b
+    #[inline(always)]
+    fn is_better<V: PartialOrd>(self, a: &V, b: &V) -> bool {
+        match self {
+            Extremum::Minimum => a < b,
+            Extremum::Maximum => a > b,
+        }
+    }
+}
+
+/**
+Finds the extremum of a unimodal function defined on a range of integers.
+
+The function must be strictly unimodal: for minimum it strictly decreases and then strictly
+increases (for maximum the opposite); only the extremum itself may be repeated.
+Instead of splitting the range into thirds, the search uses the equivalent and cheaper
+discrete form: it performs binary search for the first ```x``` where ```f(x+1)``` is not better
+than ```f(x)```. Returns the argument of the extremum and its value or None if the range is empty.
+Works with any primitive integer type.
+
+**More:** <https://en.wikipedia.org/wiki/Ternary_search>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+where n - length of the range.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::{ternary_search_int, Extremum};
+fn main(){
+    let f = |x: i64| (x - 17) * (x - 17) + 3;
+    assert_eq!(ternary_search_int(-1000..1000, f, Extremum::Minimum), Some((17, 3)));
+    //revenue of a shop depending on the price
+    let revenue = |price: u32| price * (200 - price);
+    assert_eq!(ternary_search_int(0..200, revenue, Extremum::Maximum), Some((100, 10000)));
+}
+```
+*/
+pub fn ternary_search_int<T, V, F>(range: Range<T>, mut f: F, extremum: Extremum) -> Option<(T, V)>
+    where
+        T: Integer,
+        V: PartialOrd,
+        F: FnMut(T) -> V,
+{
+    let end = range.end;
+    let arg = first_true_in_range(range, |x| {
+        match x.checked_add(T::one()) {
+            Some(next) if next < end => !extremum.is_better(&f(next), &f(x)),
+            _ => true,
+        }
+    })?;
+    Some((arg, f(arg)))
+}
+
+/**
+Finds the extremum of a unimodal slice.
+
+The slice must be strictly unimodal: for minimum it strictly decreases and then strictly
+increases (for maximum the opposite); only the extremum itself may be repeated.
+Returns the index of the extremum and a reference to it or None if the slice is empty.
+
+**More:** <https://en.wikipedia.org/wiki/Ternary_search>
+
+# Complexity
+
+- Processing complexity: O(log(n))
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::{ternary_search_slice, Extremum};
+fn main(){
+    let arr = [1, 4, 9, 12, 7, 3];
+    assert_eq!(ternary_search_slice(&arr, Extremum::Maximum), Some((3, &12)));
+    let arr = [5.0, 2.5, 0.5, 1.0];
+    assert_eq!(ternary_search_slice(&arr, Extremum::Minimum), Some((2, &0.5)));
+}
+```
+*/
+pub fn ternary_search_slice<T>(arr: &[T], extremum: Extremum) -> Option<(usize, &T)>
+    where
+        T: PartialOrd,
+{
+    ternary_search_int(0..arr.len(), |idx| &arr[idx], extremum)
+}
+
+/**
+Finds the extremum of a unimodal function of a real argument using golden-section search.
+
+The function must be strictly unimodal in ```[lo, hi]```: for minimum it strictly decreases and
+then strictly increases (for maximum the opposite). In every iteration the range shrinks by the
+golden ratio and only one new value of the function is calculated. Stops when the range is not
+longer than the given epsilon or after the given number of iterations. The search also stops
+when the range cannot shrink anymore in the precision of floating-point numbers, so a very small epsilon is safe.
+Returns the argument of the extremum and the value of the function.
+
+Panics if ```lo > hi```, if any of them is not finite, or if epsilon is not positive.
+
+**More:** <https://en.wikipedia.org/wiki/Golden-section_search>
+
+# Complexity
+
+- Processing complexity: O(log((hi-lo)/eps)) or O(iterations)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::search::{golden_section_search, Extremum, BisectLimit};
+fn main(){
+    let (x, y) = golden_section_search(0.0, 3.0, |x| x.sin(), Extremum::Maximum, BisectLimit::Epsilon(1e-9));
+    assert!((x - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((y - 1.0).abs() < 1e-9);
+}
+```
+*/
+pub fn golden_section_search<F>(lo: f64, hi: f64, mut f: F, extremum: Extremum, limit: BisectLimit) -> (f64, f64)
+    where
+        F: FnMut(f64) -> f64,
+{
+    let (eps, iterations) = validate_limit(lo, hi, limit);
+    //1/phi, the range shrinks by this ratio in every iteration
+    let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = (lo, hi);
+    let mut c = b - (b - a) * inv_phi;
+    let mut d = a + (b - a) * inv_phi;
+    let mut fc = f(c);
+    let mut fd = f(d);
+    let mut width = b - a;
+    for _ in 0..iterations {
+        if width <= eps || c <= a || d >= b {
+            //no more representable values between the interior points and the ends
+            break;
+        }
+        if extremum.is_better(&fc, &fd) {
+            //the extremum is in [a, d]
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - (b - a) * inv_phi;
+            fc = f(c);
+        } else {
+            //the extremum is in [c, b]
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + (b - a) * inv_phi;
+            fd = f(d);
+        }
+        //the range cannot shrink anymore in the floating point precision
+        if b - a >= width {
+            break;
+        }
+        width = b - a;
+    }
+    if extremum.is_better(&fd, &fc) {
+        (d, fd)
+    } else {
+        (c, fc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_every_position() {
+        for opt in 0..20i32 {
+            let f = |x: i32| (x - opt).abs();
+            assert_eq!(ternary_search_int(0..20, f, Extremum::Minimum), Some((opt, 0)));
+            assert_eq!(ternary_search_int(0..20, |x| -f(x), Extremum::Maximum), Some((opt, 0)));
+        }
+    }
+
+    #[test]
+    fn int_empty() {
+        assert_eq!(ternary_search_int(3u8..3, |x| x, Extremum::Minimum), None);
+        let arr: [i32; 0] = [];
+        assert_eq!(ternary_search_slice(&arr, Extremum::Maximum), None);
+    }
+
+    #[test]
+    fn int_whole_type() {
+        let res = ternary_search_int(i64::MIN..i64::MAX, |x| (x as i128 - 123).abs(), Extremum::Minimum);
+        assert_eq!(res, Some((123, 0)));
+    }
+
+    #[test]
+    fn int_plateau_at_extremum() {
+        let arr = [5, 3, 1, 1, 1, 2, 8];
+        assert_eq!(ternary_search_slice(&arr, Extremum::Minimum), Some((2, &1)));
+    }
+
+    #[test]
+    fn int_monotone() {
+        let arr = [1, 2, 3, 4, 5];
+        assert_eq!(ternary_search_slice(&arr, Extremum::Minimum), Some((0, &1)));
+        assert_eq!(ternary_search_slice(&arr, Extremum::Maximum), Some((4, &5)));
+    }
+
+    #[test]
+    fn golden_minimum() {
+        let (x, y) = golden_section_search(-10.0, 10.0, |x| (x - 1.5) * (x - 1.5) - 2.0, Extremum::Minimum, BisectLimit::Epsilon(1e-10));
+        assert!((x - 1.5).abs() < 1e-6);
+        assert!((y + 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn golden_iterations() {
+        let mut calls = 0;
+        golden_section_search(0.0, 1.0, |x| { calls += 1; x }, Extremum::Minimum, BisectLimit::Iterations(10));
+        assert_eq!(calls, 12);
+    }
+
+    #[test]
+    fn golden_tiny_epsilon() {
+        let mut calls = 0;
+        let (x, _) = golden_section_search(1e6, 1e7, |x| { calls += 1; (x - 1234567.0).abs() }, Extremum::Minimum, BisectLimit::Epsilon(1e-300));
+        assert!((x - 1234567.0).abs() < 1e-6);
+        assert!(calls < 1000);
+    }
+
+    #[test]
+    fn golden_boundary() {
+        let (x, _) = golden_section_search(0.0, 1.0, |x| x, Extremum::Maximum, BisectLimit::Epsilon(1e-9));
+        assert!(1.0 - x < 1e-8);
+    }
+}
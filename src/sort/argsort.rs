@@ -0,0 +1,259 @@
+use std::cmp::Ordering;
+use sort::SortingOrder;
+
+/**
+Method of assigning ranks to elements that are equal.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RankMethod {
+    ///Equal elements get the same rank, the next distinct element gets the next rank,
+    ///for example ```[10, 20, 20, 30]``` gets ranks ```[1, 2, 2, 3]```.
+    Dense,
+    ///Equal elements get the lowest rank of the group,
+    ///for example ```[10, 20, 20, 30]``` gets ranks ```[1, 2, 2, 4]```.
+    Min,
+    ///Equal elements get the average rank of the group,
+    ///for example ```[10, 20, 20, 30]``` gets ranks ```[1, 2.5, 2.5, 4]```.
+    Average
+}
+
+#[inline(always)]
+fn argsort_impl<T, F>(arr: &[T], mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering
+{
+    let mut indexes: Vec<usize> = (0..arr.len()).collect();
+    //stable sort keeps equal elements in the original order
+    indexes.sort_by(|&a, &b| cmp(&arr[a], &arr[b]));
+    indexes
+}
+
+#[inline(always)]
+fn rank_impl<T, F>(arr: &[T], mut cmp: F, method: RankMethod) -> Vec<f64>
+    where
+        F: FnMut(&T, &T) -> Ordering
+{
+    let sorted = argsort_impl(arr, &mut cmp);
+    let mut ranks = vec![0.0; arr.len()];
+    let mut dense = 0usize;
+    let mut group_start = 0usize;
+    while group_start < sorted.len() {
+        //find the end of the group of equal elements
+        let mut group_end = group_start + 1;
+        while group_end < sorted.len() && cmp(&arr[sorted[group_start]], &arr[sorted[group_end]]) == Ordering::Equal {
+            group_end += 1;
+        }
+        dense += 1;
+        let rank = match method {
+            RankMethod::Dense => dense as f64,
+            RankMethod::Min => (group_start + 1) as f64,
+            //average of ranks group_start+1 ..= group_end
+            RankMethod::Average => (group_start + 1 + group_end) as f64 / 2.0
+        };
+        for &idx in &sorted[group_start..group_end] {
+            ranks[idx] = rank;
+        }
+        group_start = group_end;
+    }
+    ranks
+}
+
+/**
+Finds the permutation that sorts the slice using a custom comparator.
+
+Returns indexes of elements in the order in which they would appear in the sorted slice.
+The slice itself is not modified. Sorting is stable - indexes of equal elements
+keep their original order.
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::argsort_by;
+
+fn main(){
+    let arr = [3.5, 1.0, 2.25, 0.5];
+    assert_eq!(argsort_by(&arr, |a, b| a.partial_cmp(b).unwrap()), vec![3, 1, 2, 0]);
+}
+```
+*/
+pub fn argsort_by<T, F>(arr: &[T], cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering
+{
+    argsort_impl(arr, cmp)
+}
+
+/**
+Finds the permutation that sorts the slice.
+
+Returns indexes of elements in the order in which they would appear in the sorted slice.
+The slice itself is not modified. Sorting is stable - indexes of equal elements
+keep their original order.
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::argsort;
+use algorithm::sort::SortingOrder::*;
+
+fn main(){
+    let arr = ['c', 'a', 'd', 'b'];
+    assert_eq!(argsort(&arr, Ascending), vec![1, 3, 0, 2]);
+    assert_eq!(argsort(&arr, Descending), vec![2, 0, 3, 1]);
+}
+```
+*/
+pub fn argsort<T>(arr: &[T], order: SortingOrder) -> Vec<usize>
+    where
+        T: Ord
+{
+    match order {
+        SortingOrder::Ascending => argsort_impl(arr, |a, b| a.cmp(b)),
+        SortingOrder::Descending => argsort_impl(arr, |a, b| b.cmp(a))
+    }
+}
+
+/**
+Calculates ranks of elements in the slice using a custom comparator.
+
+The smallest element gets rank 1. Ranks of equal elements are assigned according
+to the provided method.
+
+**More:** <https://en.wikipedia.org/wiki/Ranking>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::{rank_by, RankMethod};
+
+fn main(){
+    let arr = [0.5, 0.25, 0.5, 1.0];
+    assert_eq!(rank_by(&arr, |a, b| a.partial_cmp(b).unwrap(), RankMethod::Min), vec![2.0, 1.0, 2.0, 4.0]);
+}
+```
+*/
+pub fn rank_by<T, F>(arr: &[T], cmp: F, method: RankMethod) -> Vec<f64>
+    where
+        F: FnMut(&T, &T) -> Ordering
+{
+    rank_impl(arr, cmp, method)
+}
+
+/**
+Calculates ranks of elements in the slice.
+
+The first element in the given sorting order gets rank 1. Ranks of equal elements are assigned
+according to the provided method.
+
+**More:** <https://en.wikipedia.org/wiki/Ranking>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::{rank, RankMethod};
+use algorithm::sort::SortingOrder::*;
+
+fn main(){
+    let arr = [10, 20, 20, 30];
+    assert_eq!(rank(&arr, Ascending, RankMethod::Dense), vec![1.0, 2.0, 2.0, 3.0]);
+    assert_eq!(rank(&arr, Ascending, RankMethod::Min), vec![1.0, 2.0, 2.0, 4.0]);
+    assert_eq!(rank(&arr, Ascending, RankMethod::Average), vec![1.0, 2.5, 2.5, 4.0]);
+    assert_eq!(rank(&arr, Descending, RankMethod::Min), vec![4.0, 2.0, 2.0, 1.0]);
+}
+```
+*/
+pub fn rank<T>(arr: &[T], order: SortingOrder, method: RankMethod) -> Vec<f64>
+    where
+        T: Ord
+{
+    match order {
+        SortingOrder::Ascending => rank_impl(arr, |a, b| a.cmp(b), method),
+        SortingOrder::Descending => rank_impl(arr, |a, b| b.cmp(a), method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::SortingOrder::*;
+
+    #[test]
+    fn argsort_empty() {
+        let arr: [i32; 0] = [];
+        assert!(argsort(&arr, Ascending).is_empty());
+    }
+
+    #[test]
+    fn argsort_asc() {
+        let arr = [5, 3, 1, 4, 2];
+        assert_eq!(argsort(&arr, Ascending), vec![2, 4, 1, 3, 0]);
+    }
+
+    #[test]
+    fn argsort_desc() {
+        let arr = [5, 3, 1, 4, 2];
+        assert_eq!(argsort(&arr, Descending), vec![0, 3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn argsort_stable() {
+        let arr = [2, 1, 2, 1, 2];
+        assert_eq!(argsort(&arr, Ascending), vec![1, 3, 0, 2, 4]);
+        assert_eq!(argsort(&arr, Descending), vec![0, 2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn argsort_by_key() {
+        let arr = ["ccc", "a", "bb"];
+        assert_eq!(argsort_by(&arr, |a, b| a.len().cmp(&b.len())), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn rank_empty() {
+        let arr: [i32; 0] = [];
+        assert!(rank(&arr, Ascending, RankMethod::Average).is_empty());
+    }
+
+    #[test]
+    fn rank_distinct() {
+        let arr = [30, 10, 20];
+        assert_eq!(rank(&arr, Ascending, RankMethod::Dense), vec![3.0, 1.0, 2.0]);
+        assert_eq!(rank(&arr, Ascending, RankMethod::Min), vec![3.0, 1.0, 2.0]);
+        assert_eq!(rank(&arr, Ascending, RankMethod::Average), vec![3.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn rank_ties() {
+        let arr = [7, 3, 7, 3, 7, 1];
+        assert_eq!(rank(&arr, Ascending, RankMethod::Dense), vec![3.0, 2.0, 3.0, 2.0, 3.0, 1.0]);
+        assert_eq!(rank(&arr, Ascending, RankMethod::Min), vec![4.0, 2.0, 4.0, 2.0, 4.0, 1.0]);
+        assert_eq!(rank(&arr, Ascending, RankMethod::Average), vec![5.0, 2.5, 5.0, 2.5, 5.0, 1.0]);
+    }
+
+    #[test]
+    fn rank_desc() {
+        let arr = [7, 3, 7, 3, 7, 1];
+        assert_eq!(rank(&arr, Descending, RankMethod::Dense), vec![1.0, 2.0, 1.0, 2.0, 1.0, 3.0]);
+        assert_eq!(rank(&arr, Descending, RankMethod::Min), vec![1.0, 4.0, 1.0, 4.0, 1.0, 6.0]);
+    }
+}
@@ -0,0 +1,357 @@
+/**
+Iterator over all k-element combinations of the provided elements.
+
+Combinations are generated in the lexicographic order of indexes of the chosen elements,
+elements inside every combination keep their original order.
+Elements are treated as distinct even if they are equal.
+
+**More:** <https://en.wikipedia.org/wiki/Combination>
+
+# Complexity
+
+- Processing complexity: O(k) per combination
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::Combinations;
+
+fn main(){
+    let combs: Vec<Vec<char>> = Combinations::new(vec!['a', 'b', 'c', 'd'], 2).collect();
+    assert_eq!(combs.len(), 6);
+    assert_eq!(combs[0], vec!['a', 'b']);
+    assert_eq!(combs[5], vec!['c', 'd']);
+}
+```
+*/
+pub struct Combinations<T> where T: Clone {
+    elems: Vec<T>,
+    indexes: Vec<usize>,
+    done: bool,
+    started: bool
+}
+
+impl<T> Combinations<T> where T: Clone {
+    pub fn new<I>(iter: I, k: usize) -> Self where I: IntoIterator<Item=T> {
+        let elems: Vec<T> = iter.into_iter().collect();
+        let done = k > elems.len();
+        Self {
+            elems,
+            indexes: (0..k).collect(),
+            done,
+            started: false
+        }
+    }
+
+    fn current(&self) -> Vec<T> {
+        self.indexes.iter().map(|&i| self.elems[i].clone()).collect()
+    }
+}
+
+impl<T> Iterator for Combinations<T> where T: Clone {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(self.current());
+        }
+        let n = self.elems.len();
+        let k = self.indexes.len();
+        //find the rightmost index that can still be moved forward
+        let pos = match (0..k).rev().find(|&i| self.indexes[i] < n - k + i) {
+            Some(p) => p,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        self.indexes[pos] += 1;
+        for i in pos + 1..k {
+            self.indexes[i] = self.indexes[i - 1] + 1;
+        }
+        Some(self.current())
+    }
+}
+
+/**
+Iterator over all k-element combinations with repetitions of the provided elements.
+
+Every element can be chosen multiple times. Combinations are generated in the lexicographic
+order of indexes of the chosen elements.
+
+**More:** <https://en.wikipedia.org/wiki/Combination#Number_of_combinations_with_repetition>
+
+# Complexity
+
+- Processing complexity: O(k) per combination
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::CombinationsWithReplacement;
+
+fn main(){
+    let combs: Vec<Vec<i32>> = CombinationsWithReplacement::new(vec![1, 2, 3], 2).collect();
+    assert_eq!(combs, vec![vec![1, 1], vec![1, 2], vec![1, 3], vec![2, 2], vec![2, 3], vec![3, 3]]);
+}
+```
+*/
+pub struct CombinationsWithReplacement<T> where T: Clone {
+    elems: Vec<T>,
+    indexes: Vec<usize>,
+    done: bool,
+    started: bool
+}
+
+impl<T> CombinationsWithReplacement<T> where T: Clone {
+    pub fn new<I>(iter: I, k: usize) -> Self where I: IntoIterator<Item=T> {
+        let elems: Vec<T> = iter.into_iter().collect();
+        //nothing can be chosen from an empty collection, but an empty combination always exists
+        let done = elems.is_empty() && k > 0;
+        Self {
+            elems,
+            indexes: vec![0; k],
+            done,
+            started: false
+        }
+    }
+
+    fn current(&self) -> Vec<T> {
+        self.indexes.iter().map(|&i| self.elems[i].clone()).collect()
+    }
+}
+
+impl<T> Iterator for CombinationsWithReplacement<T> where T: Clone {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(self.current());
+        }
+        let n = self.elems.len();
+        let pos = match self.indexes.iter().rposition(|&i| i + 1 < n) {
+            Some(p) => p,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        let val = self.indexes[pos] + 1;
+        for idx in &mut self.indexes[pos..] {
+            *idx = val;
+        }
+        Some(self.current())
+    }
+}
+
+/**
+Iterator over all subsets of the provided elements.
+
+Subsets are generated in the order of bit masks: the subset number m contains
+the element i if the bit i of m is set. Elements inside every subset keep their original order.
+
+Panics if there are too many elements to enumerate 2ⁿ subsets using ```usize```.
+
+**More:** <https://en.wikipedia.org/wiki/Power_set>
+
+# Complexity
+
+- Processing complexity: O(n) per subset
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::PowerSet;
+
+fn main(){
+    let subsets: Vec<Vec<char>> = PowerSet::new(vec!['a', 'b']).collect();
+    assert_eq!(subsets, vec![vec![], vec!['a'], vec!['b'], vec!['a', 'b']]);
+}
+```
+*/
+pub struct PowerSet<T> where T: Clone {
+    elems: Vec<T>,
+    mask: usize,
+    done: bool
+}
+
+impl<T> PowerSet<T> where T: Clone {
+    pub fn new<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
+        let elems: Vec<T> = iter.into_iter().collect();
+        if elems.len() >= 8 * ::std::mem::size_of::<usize>() {
+            panic!("Too many elements to generate the power set.");
+        }
+        Self {
+            elems,
+            mask: 0,
+            done: false
+        }
+    }
+}
+
+impl<T> Iterator for PowerSet<T> where T: Clone {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if self.done {
+            return None;
+        }
+        let mask = self.mask;
+        let subset = self.elems.iter()
+            .enumerate()
+            .filter(|&(i, _)| mask & (1 << i) != 0)
+            .map(|(_, e)| e.clone())
+            .collect();
+        if mask == (1 << self.elems.len()) - 1 {
+            self.done = true;
+        } else {
+            self.mask += 1;
+        }
+        Some(subset)
+    }
+}
+
+/**
+Iterator over the Cartesian product of the provided collections.
+
+Every generated tuple contains one element of every collection. Tuples are generated
+in the lexicographic order - the last collection changes the fastest.
+
+**More:** <https://en.wikipedia.org/wiki/Cartesian_product>
+
+# Complexity
+
+- Processing complexity: O(k) per tuple
+- Memory complexity: O(n)
+
+where k - number of collections, n - total number of elements.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::CartesianProduct;
+
+fn main(){
+    let product: Vec<Vec<i32>> = CartesianProduct::new(vec![vec![1, 2], vec![3], vec![4, 5]]).collect();
+    assert_eq!(product, vec![vec![1, 3, 4], vec![1, 3, 5], vec![2, 3, 4], vec![2, 3, 5]]);
+}
+```
+*/
+pub struct CartesianProduct<T> where T: Clone {
+    pools: Vec<Vec<T>>,
+    indexes: Vec<usize>,
+    done: bool,
+    started: bool
+}
+
+impl<T> CartesianProduct<T> where T: Clone {
+    pub fn new<I, J>(pools: I) -> Self
+        where
+            I: IntoIterator<Item=J>,
+            J: IntoIterator<Item=T>
+    {
+        let pools: Vec<Vec<T>> = pools.into_iter().map(|p| p.into_iter().collect()).collect();
+        let done = pools.iter().any(|p| p.is_empty());
+        let indexes = vec![0; pools.len()];
+        Self {
+            pools,
+            indexes,
+            done,
+            started: false
+        }
+    }
+
+    fn current(&self) -> Vec<T> {
+        self.indexes.iter().zip(self.pools.iter()).map(|(&i, p)| p[i].clone()).collect()
+    }
+}
+
+impl<T> Iterator for CartesianProduct<T> where T: Clone {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(self.current());
+        }
+        //increment indexes like an odometer
+        for pos in (0..self.pools.len()).rev() {
+            self.indexes[pos] += 1;
+            if self.indexes[pos] < self.pools[pos].len() {
+                return Some(self.current());
+            }
+            self.indexes[pos] = 0;
+        }
+        self.done = true;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comb_count() {
+        assert_eq!(Combinations::new(0..10, 3).count(), 120);
+        assert_eq!(Combinations::new(0..5, 5).count(), 1);
+    }
+
+    #[test]
+    fn comb_edge() {
+        assert_eq!(Combinations::new(0..3, 0).collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+        assert_eq!(Combinations::new(0..3, 4).count(), 0);
+    }
+
+    #[test]
+    fn comb_order() {
+        let combs: Vec<Vec<i32>> = Combinations::new(vec![1, 2, 3, 4], 3).collect();
+        assert_eq!(combs, vec![vec![1, 2, 3], vec![1, 2, 4], vec![1, 3, 4], vec![2, 3, 4]]);
+    }
+
+    #[test]
+    fn comb_rep_count() {
+        //C(n+k-1, k) = C(6, 3)
+        assert_eq!(CombinationsWithReplacement::new(0..4, 3).count(), 20);
+    }
+
+    #[test]
+    fn comb_rep_edge() {
+        assert_eq!(CombinationsWithReplacement::new(0..0, 2).count(), 0);
+        assert_eq!(CombinationsWithReplacement::new(0..0, 0).count(), 1);
+        assert_eq!(CombinationsWithReplacement::new(vec![7], 3).collect::<Vec<_>>(), vec![vec![7, 7, 7]]);
+    }
+
+    #[test]
+    fn power_set_count() {
+        assert_eq!(PowerSet::new(0..10).count(), 1024);
+        assert_eq!(PowerSet::new(0..0).collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn product_count() {
+        assert_eq!(CartesianProduct::new(vec![0..3, 0..4, 0..5]).count(), 60);
+    }
+
+    #[test]
+    fn product_edge() {
+        let empty_pool: Vec<Vec<i32>> = vec![vec![1, 2], vec![]];
+        assert_eq!(CartesianProduct::new(empty_pool).count(), 0);
+        let no_pools: Vec<Vec<i32>> = vec![];
+        assert_eq!(CartesianProduct::new(no_pools).collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+    }
+}
@@ -58,11 +58,65 @@ pub fn longest_ordered_subsequence<T>(arr: &[T], order: Order) -> Vec<usize>
     where
         T: Ord,
 {
-    match order{
-        Order::Increasing => longest_subsequence_impl(arr, |a, b| a<b),
-        Order::Decreasing => longest_subsequence_impl(arr, |a, b| a>b),
-        Order::NotDecreasing => longest_subsequence_impl(arr, |a, b| a<=b),
-        Order::NotIncreasing => longest_subsequence_impl(arr, |a, b| a>=b),
+    longest_subsequence_impl(arr, order_fn(order))
+}
+
+//patience sorting step shared by all variants
+struct Patience<K> {
+    //tails[l] - index and key of the best last element of an ordered subsequence of length l+1
+    tails: Vec<(usize, K)>,
+    //previous element of the longest ordered subsequence ending with the given element
+    prev: Vec<Option<usize>>,
+}
+
+impl<K> Patience<K> {
+    fn new() -> Self {
+        Patience {
+            tails: Vec::new(),
+            prev: Vec::new(),
+        }
+    }
+
+    //adds the next element, returns the length of the longest ordered subsequence ending with it
+    fn push<F>(&mut self, key: K, is_ordered: &mut F) -> usize
+        where
+            F: FnMut(&K, &K) -> bool
+    {
+        let idx = self.prev.len();
+        //the first tail that cannot be followed by the new element gets replaced
+        let pos = binary_first_by(&self.tails, |t| !is_ordered(&t.1, &key)).unwrap_or(self.tails.len());
+        self.prev.push(if pos > 0 { Some(self.tails[pos - 1].0) } else { None });
+        if pos == self.tails.len() {
+            self.tails.push((idx, key));
+        } else {
+            self.tails[pos] = (idx, key);
+        }
+        pos + 1
+    }
+
+    //indexes of the subsequence ending with the given element
+    fn chain(&self, last: Option<usize>) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut curr = last;
+        while let Some(idx) = curr {
+            result.push(idx);
+            curr = self.prev[idx];
+        }
+        result.reverse();
+        result
+    }
+
+    fn longest(&self) -> Vec<usize> {
+        self.chain(self.tails.last().map(|t| t.0))
+    }
+}
+
+fn order_fn<T: Ord>(order: Order) -> fn(&T, &T) -> bool {
+    match order {
+        Order::Increasing => |a, b| a < b,
+        Order::Decreasing => |a, b| a > b,
+        Order::NotDecreasing => |a, b| a <= b,
+        Order::NotIncreasing => |a, b| a >= b,
     }
 }
 
@@ -71,59 +125,342 @@ fn longest_subsequence_impl<T, F>(arr: &[T], mut is_ordered:F) -> Vec<usize>
     where
         F: FnMut(&T, &T) -> bool
 {
-    //adapted from https://codereview.stackexchange.com/questions/187337/longest-increasing-subsequence-algorithm
+    let mut patience = Patience::new();
+    for elem in arr {
+        patience.push(elem, &mut |a: &&T, b: &&T| is_ordered(*a, *b));
+    }
+    patience.longest()
+}
+
+/**
+Find indexes of the longest ordered subsequence of elements produced by an iterator, ordered by the key.
+
+Elements are processed one by one and only keys of at most one element per possible
+subsequence length are kept, so this works well with streams of data.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
 
-    let mut result = Vec::new();
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_ordered_subsequence_by_key;
+use algorithm::sort::Order::*;
 
-    //if arr is empty, then the result is too
-    if arr.is_empty() {
-        return result;
+fn main(){
+     let words = "a quick brown fox jumps over the lazy dog".split(' ');
+     //words with not decreasing lengths: a, fox, the, dog
+     assert_eq!(longest_ordered_subsequence_by_key(words, |w| w.len(), NotDecreasing), vec![0, 3, 6, 8]);
+}
+```
+*/
+pub fn longest_ordered_subsequence_by_key<I, K, F>(iter: I, mut key: F, order: Order) -> Vec<usize>
+    where
+        I: IntoIterator,
+        K: Ord,
+        F: FnMut(&I::Item) -> K,
+{
+    let mut is_ordered = order_fn::<K>(order);
+    let mut patience = Patience::new();
+    for elem in iter {
+        patience.push(key(&elem), &mut is_ordered);
     }
+    patience.longest()
+}
 
-    // This vector stores, for each item,
-    // the index of the largest item prior to itself that is smaller than itself.
-    // We'll use this vector at the end to build the final result.
-    let mut previous_chain = vec![0; arr.len()];
+/**
+Find lengths of the longest ordered subsequences ending at every index using custom comparator.
 
-    // Initially, we assume that the first item is part of the result.
-    // We will replace this index later if that's not the case.
-    result.push(0);
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
 
-    for i in 1..arr.len() {
-        // If the next item is greater than the last item of the current longest subsequence,
-        // push its index at the end of the result and continue.
-        if is_ordered(&arr[*result.last().unwrap()], &arr[i]) {
-            previous_chain[i] = *result.last().unwrap();
-            result.push(i);
-            continue;
-        }
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
 
-        // We want to overwrite an index that refers to the smallest item that is larger than arr[i].
-        // If there is no such item, then we do nothing.
-        if let Some(next_element_index) = binary_first_by(&result, |a |  is_ordered(&arr[i], &arr[*a]) ) {
-            if next_element_index>0 {
-                previous_chain[i] = result[next_element_index - 1];
-            }
-            result[next_element_index] = i;
-        }
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_ordered_subsequence_lengths_by;
 
+fn main(){
+     let arr  = [3,1,4,1,5,9,2,6];
+     assert_eq!(longest_ordered_subsequence_lengths_by(&arr, |a,b| a<b), vec![1,1,2,1,3,4,2,4]);
+}
+```
+*/
+pub fn longest_ordered_subsequence_lengths_by<T, F>(arr: &[T], mut is_ordered:F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    let mut patience = Patience::new();
+    arr.iter().map(|elem| patience.push(elem, &mut |a: &&T, b: &&T| is_ordered(*a, *b))).collect()
+}
 
+/**
+Find lengths of the longest ordered subsequences ending at every index.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_ordered_subsequence_lengths;
+use algorithm::sort::Order::*;
+
+fn main(){
+     let arr  = [3,1,4,1,5,9,2,6];
+     assert_eq!(longest_ordered_subsequence_lengths(&arr, NotDecreasing), vec![1,1,2,2,3,4,3,4]);
+}
+```
+*/
+pub fn longest_ordered_subsequence_lengths<T>(arr: &[T], order: Order) -> Vec<usize>
+    where
+        T: Ord,
+{
+    longest_ordered_subsequence_lengths_by(arr, order_fn(order))
+}
+
+//counts longest ordered subsequences, add and sub work on counts
+fn count_impl<T, F, C, A, S>(arr: &[T], mut is_ordered: F, one: C, add: A, sub: S) -> Option<C>
+    where
+        F: FnMut(&T, &T) -> bool,
+        C: Copy,
+        A: Fn(C, C) -> C,
+        S: Fn(C, C) -> C,
+{
+    let mut patience = Patience::new();
+    //piles[l] - elements ending ordered subsequences of length l+1 in the order of appearance,
+    //with cumulative numbers of such subsequences; elements that can precede a new one form a suffix of a pile
+    let mut piles: Vec<Vec<(usize, C)>> = Vec::new();
+    for (i, elem) in arr.iter().enumerate() {
+        let len = patience.push(elem, &mut |a: &&T, b: &&T| is_ordered(*a, *b));
+        let count = if len == 1 {
+            one
+        } else {
+            let pile = &piles[len - 2];
+            let from = binary_first_by(pile, |e| is_ordered(&arr[e.0], elem)).unwrap_or(pile.len());
+            let total = pile[pile.len() - 1].1;
+            if from == 0 { total } else { sub(total, pile[from - 1].1) }
+        };
+        if piles.len() < len {
+            piles.push(Vec::new());
+        }
+        let pile = &mut piles[len - 1];
+        let cumulative = match pile.last() {
+            Some(last) => add(last.1, count),
+            None => count,
+        };
+        pile.push((i, cumulative));
     }
-    // The last item in `result` is correct,
-    // but we might have started overwriting earlier items
-    // with what could have been a longer subsequence.
-    // Walk back `previous_chain` to restore the proper subsequence.
-    let mut u = result.len();
-    let mut v = *result.last().unwrap();
-    while u != 0 {
-        u -= 1;
-        result[u] = v;
-        v = previous_chain[v];
-    }
+    piles.last().and_then(|pile| pile.last()).map(|last| last.1)
+}
+
+/**
+Count the longest ordered subsequences in the slice using custom comparator.
+
+Subsequences are distinguished by indexes, so equal elements at different positions form
+different subsequences. The count of an empty slice is 0. Panics if the count does not fit
+into ```u128```, use ```count_longest_ordered_subsequences_mod_by()``` for long slices.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_longest_ordered_subsequences_by;
+
+fn main(){
+     //[1,3,4,7], [1,3,5,7]
+     assert_eq!(count_longest_ordered_subsequences_by(&[1,3,5,4,7], |a,b| a<b), 2);
+}
+```
+*/
+pub fn count_longest_ordered_subsequences_by<T, F>(arr: &[T], is_ordered: F) -> u128
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    count_impl(arr, is_ordered, 1u128, |a, b| a.checked_add(b).expect("Count overflow"), |a, b| a - b).unwrap_or(0)
+}
+
+/**
+Count the longest ordered subsequences in the slice.
+
+See ```count_longest_ordered_subsequences_by()``` for details.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_longest_ordered_subsequences;
+use algorithm::sort::Order::*;
+
+fn main(){
+     assert_eq!(count_longest_ordered_subsequences(&[2,2,2], Increasing), 3);
+     assert_eq!(count_longest_ordered_subsequences(&[2,2,2], NotDecreasing), 1);
+}
+```
+*/
+pub fn count_longest_ordered_subsequences<T>(arr: &[T], order: Order) -> u128
+    where
+        T: Ord,
+{
+    count_longest_ordered_subsequences_by(arr, order_fn(order))
+}
+
+/**
+Count the longest ordered subsequences in the slice modulo the given number using custom comparator.
+
+See ```count_longest_ordered_subsequences_by()``` for details. Panics if the modulus is 0.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_longest_ordered_subsequences_mod_by;
+
+fn main(){
+     //every pair of consecutive elements can be swapped: 2^100 subsequences
+     let arr: Vec<u32> = (0..100).flat_map(|i| vec![2*i+1, 2*i]).collect();
+     assert_eq!(count_longest_ordered_subsequences_mod_by(&arr, 1_000_000_007, |a,b| a<b), 976_371_285);
+}
+```
+*/
+pub fn count_longest_ordered_subsequences_mod_by<T, F>(arr: &[T], modulus: u64, is_ordered: F) -> u64
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    assert!(modulus > 0, "Modulus must be positive");
+    count_impl(arr, is_ordered, 1 % modulus, |a, b| ((a as u128 + b as u128) % modulus as u128) as u64,
+               |a, b| ((a as u128 + modulus as u128 - b as u128) % modulus as u128) as u64).unwrap_or(0)
+}
+
+/**
+Count the longest ordered subsequences in the slice modulo the given number.
+
+See ```count_longest_ordered_subsequences_by()``` for details. Panics if the modulus is 0.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_longest_ordered_subsequences_mod;
+use algorithm::sort::Order::*;
+
+fn main(){
+     assert_eq!(count_longest_ordered_subsequences_mod(&[2,1,4,3,6,5], 5, Increasing), 3);
+}
+```
+*/
+pub fn count_longest_ordered_subsequences_mod<T>(arr: &[T], modulus: u64, order: Order) -> u64
+    where
+        T: Ord,
+{
+    count_longest_ordered_subsequences_mod_by(arr, modulus, order_fn(order))
+}
+
+/**
+Find indexes of the longest bitonic subsequence in the slice using custom comparator.
+
+A bitonic subsequence is ordered up to its peak element and ordered in the reverse direction after it,
+e.g. first increasing and then decreasing. Either part may be empty.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_bitonic_subsequence_by;
+
+fn main(){
+     let arr  = [1,11,2,10,4,5,2,1];
+     //[1,2,10,4,2,1] or [1,2,4,5,2,1]
+     assert_eq!(longest_bitonic_subsequence_by(&arr, |a,b| a<b).len(), 6);
+}
+```
+*/
+pub fn longest_bitonic_subsequence_by<T, F>(arr: &[T], mut is_ordered:F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    let n = arr.len();
+    let mut rising = Patience::new();
+    let up: Vec<usize> = arr.iter().map(|elem| rising.push(elem, &mut |a: &&T, b: &&T| is_ordered(*a, *b))).collect();
+    //the falling part is ordered when read from the end
+    let mut falling = Patience::new();
+    let mut down: Vec<usize> = arr.iter().rev().map(|elem| falling.push(elem, &mut |a: &&T, b: &&T| is_ordered(*a, *b))).collect();
+    down.reverse();
+    let peak = match (0..n).max_by_key(|&i| (up[i] + down[i], n - i)) {
+        Some(peak) => peak,
+        None => return Vec::new(),
+    };
+    let mut result = rising.chain(Some(peak));
+    let after = falling.chain(Some(n - 1 - peak));
+    result.extend(after.iter().rev().skip(1).map(|&i| n - 1 - i));
     result
 }
 
+/**
+Find indexes of the longest bitonic subsequence in the slice.
+
+For ```Increasing``` order the subsequence is first increasing and then decreasing,
+for ```Decreasing``` - first decreasing and then increasing. The peak element is always
+included in the first part.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
 
+# Complexity
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_bitonic_subsequence;
+use algorithm::sort::Order::*;
+
+fn main(){
+     let arr  = [5,1,4,2,3,0];
+     assert_eq!(longest_bitonic_subsequence(&arr, Decreasing), vec![0,1,3,4]);
+     assert_eq!(longest_bitonic_subsequence(&arr, Increasing), vec![0,2,3,5]);
+}
+```
+*/
+pub fn longest_bitonic_subsequence<T>(arr: &[T], order: Order) -> Vec<usize>
+    where
+        T: Ord,
+{
+    longest_bitonic_subsequence_by(arr, order_fn(order))
+}
 
 #[cfg(test)]
 mod tests {
@@ -214,4 +551,94 @@ mod tests {
         assert_eq!(longest_ordered_subsequence(&arr, NotIncreasing), vec![0, 2, 4, 5, 7, 8, 9]);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn ndecr_equal_tail() {
+        let arr  = [1,3,5,3,4];
+        assert_eq!(longest_ordered_subsequence(&arr, NotDecreasing), vec![0, 1, 3, 4]);
+    }
+
+    //all sequences of the given length with elements from 0..3
+    fn all_small(len: usize) -> Vec<Vec<u8>> {
+        (0..3usize.pow(len as u32)).map(|mut code| {
+            (0..len).map(|_| {
+                let digit = (code % 3) as u8;
+                code /= 3;
+                digit
+            }).collect()
+        }).collect()
+    }
+
+    //lengths of all ordered subsequences given as bit masks of indexes
+    fn naive_lengths(arr: &[u8], order: Order) -> Vec<usize> {
+        let is_ordered = order_fn::<u8>(order);
+        (0..1usize << arr.len()).filter_map(|mask| {
+            let seq: Vec<u8> = (0..arr.len()).filter(|i| mask >> i & 1 == 1).map(|i| arr[i]).collect();
+            if seq.windows(2).all(|w| is_ordered(&w[0], &w[1])) { Some(seq.len()) } else { None }
+        }).collect()
+    }
+
+    fn is_valid(arr: &[u8], idx: &[usize], order: Order) -> bool {
+        let is_ordered = order_fn::<u8>(order);
+        idx.windows(2).all(|w| w[0] < w[1] && is_ordered(&arr[w[0]], &arr[w[1]]))
+    }
+
+    #[test]
+    fn matches_naive() {
+        for len in 0..7 {
+            for arr in all_small(len) {
+                for k in 0..4 {
+                    let order = || match k { 0 => Increasing, 1 => Decreasing, 2 => NotDecreasing, _ => NotIncreasing };
+                    let lengths = naive_lengths(&arr, order());
+                    let best = lengths.iter().cloned().max().unwrap();
+                    let lis = longest_ordered_subsequence(&arr, order());
+                    assert_eq!(lis.len(), best);
+                    assert!(is_valid(&arr, &lis, order()));
+                    assert_eq!(longest_ordered_subsequence_by_key(arr.iter(), |&&x| x, order()), lis);
+                    let count = lengths.iter().filter(|&&l| l == best).count() as u128;
+                    let count = if len == 0 { 0 } else { count };
+                    assert_eq!(count_longest_ordered_subsequences(&arr, order()), count);
+                    assert_eq!(count_longest_ordered_subsequences_mod(&arr, 7, order()) as u128, count % 7);
+                    let ending = longest_ordered_subsequence_lengths(&arr, order());
+                    //quadratic dynamic programming
+                    let is_ordered = order_fn::<u8>(order());
+                    let mut expected: Vec<usize> = Vec::new();
+                    for i in 0..len {
+                        let best = (0..i).filter(|&j| is_ordered(&arr[j], &arr[i])).map(|j| expected[j]).max();
+                        expected.push(best.unwrap_or(0) + 1);
+                    }
+                    assert_eq!(ending, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bitonic_matches_naive() {
+        for len in 0..7 {
+            for arr in all_small(len) {
+                let bitonic = longest_bitonic_subsequence(&arr, Increasing);
+                let peak = (0..bitonic.len()).find(|&p| {
+                    is_valid(&arr, &bitonic[..p + 1], Increasing) && is_valid(&arr, &bitonic[p..], Decreasing)
+                });
+                assert!(bitonic.is_empty() || peak.is_some());
+                //best split point: longest increasing prefix and longest decreasing suffix ending and starting at i
+                let best = (0..len).map(|i| {
+                    let up = longest_ordered_subsequence_lengths(&arr[..i + 1], Increasing)[i];
+                    let mut rev = arr[i..].to_vec();
+                    rev.reverse();
+                    let down = longest_ordered_subsequence_lengths(&rev, Increasing)[len - 1 - i];
+                    up + down - 1
+                }).max().unwrap_or(0);
+                assert_eq!(bitonic.len(), best);
+            }
+        }
+    }
+
+    #[test]
+    fn count_large() {
+        let arr: Vec<u32> = (0..60).flat_map(|i| vec![2*i+1, 2*i]).collect();
+        assert_eq!(count_longest_ordered_subsequences(&arr, Increasing), 1u128 << 60);
+        assert_eq!(count_longest_ordered_subsequences_mod(&arr, 1_000_000_007, Increasing), (1u64 << 60) % 1_000_000_007);
+    }
+
+}
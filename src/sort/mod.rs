@@ -10,15 +10,25 @@ mod longest_subsequence;
 mod order;
 mod shuffle;
 mod quick_sort;
+mod argsort;
+mod permutation;
+mod sortedness;
+mod combinations;
+mod runs;
 
 pub use self::order::{SortingOrder, Order};
 pub use self::counting_sort::{counting_sort_by, counting_sort};
 pub use self::first_unordered::{first_unordered, first_unordered_by};
 pub use self::is_ordered::{is_ordered, is_ordered_by};
 pub use self::longest_substring::{longest_ordered_substring_idx, longest_ordered_substring_idx_by, longest_ordered_substring, longest_ordered_substring_by};
-pub use self::shuffle::shuffle;
-pub use self::quick_sort::{quick_sort_by, quick_sort, quick_sort_rand_by, quick_sort_rand};
-pub use self::longest_subsequence::{longest_ordered_subsequence, longest_ordered_subsequence_by};
-
-
-
+pub use self::shuffle::{shuffle, shuffle_with_rng, choose_multiple, choose_multiple_with_rng};
+pub use self::quick_sort::{quick_sort_by, quick_sort, quick_sort_rand_by, quick_sort_rand, quick_sort_rand_by_with_rng, quick_sort_rand_with_rng};
+pub use self::longest_subsequence::{longest_ordered_subsequence, longest_ordered_subsequence_by, longest_ordered_subsequence_by_key,
+    longest_ordered_subsequence_lengths, longest_ordered_subsequence_lengths_by, count_longest_ordered_subsequences,
+    count_longest_ordered_subsequences_by, count_longest_ordered_subsequences_mod, count_longest_ordered_subsequences_mod_by,
+    longest_bitonic_subsequence, longest_bitonic_subsequence_by};
+pub use self::argsort::{argsort, argsort_by, rank, rank_by, RankMethod};
+pub use self::permutation::{apply_permutation, invert_permutation, next_permutation, next_permutation_by, prev_permutation, prev_permutation_by, permutation_rank, permutation_unrank, Permutations};
+pub use self::combinations::{Combinations, CombinationsWithReplacement, PowerSet, CartesianProduct};
+pub use self::sortedness::{count_inversions, count_inversions_by, kendall_tau_distance, count_runs, count_runs_by, min_removals_to_order, min_removals_to_order_by};
+pub use self::runs::{runs_by, longest_run_by_key, longest_substring_where, Runs};
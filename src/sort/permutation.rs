@@ -0,0 +1,609 @@
+use sort::Order;
+
+#[inline(always)]
+fn check_index(idx: usize, len: usize) {
+    if idx >= len {
+        panic!("Index {} is outside of the permutation of length {}.", idx, len);
+    }
+}
+
+/**
+Reorders elements of the slice in place according to the provided permutation.
+
+After the call the element at index ```i``` is the element that was previously at index
+```perm[i]```. This makes it possible to sort a slice using indexes returned by ```argsort()```.
+
+The permutation is applied by decomposing it into cycles, so every element is moved only once.
+
+Panics if the slice and the permutation have different lengths or if ```perm``` is not
+a valid permutation.
+
+**More:** <https://en.wikipedia.org/wiki/Cyclic_permutation>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::{apply_permutation, argsort};
+use algorithm::sort::SortingOrder::*;
+
+fn main(){
+    let mut arr = ['c', 'a', 'd', 'b'];
+    let perm = argsort(&arr, Ascending);
+    apply_permutation(&mut arr, &perm);
+    assert_eq!(arr, ['a', 'b', 'c', 'd']);
+}
+```
+*/
+pub fn apply_permutation<T>(arr: &mut [T], perm: &[usize]) {
+    if arr.len() != perm.len() {
+        panic!("The slice and the permutation have different lengths.");
+    }
+    let mut visited = vec![false; perm.len()];
+    for start in 0..perm.len() {
+        if visited[start] {
+            continue;
+        }
+        //walk the cycle, pulling every element to its destination
+        let mut curr = start;
+        loop {
+            visited[curr] = true;
+            let next = perm[curr];
+            check_index(next, perm.len());
+            if next == start {
+                break;
+            }
+            if visited[next] {
+                panic!("The provided sequence is not a valid permutation.");
+            }
+            arr.swap(curr, next);
+            curr = next;
+        }
+    }
+}
+
+/**
+Calculates the inverse of the provided permutation.
+
+If ```perm[i] == j``` then ```inverse[j] == i```. Inverting the result of ```argsort()```
+gives the position of every element in the sorted slice.
+
+Panics if ```perm``` is not a valid permutation.
+
+**More:** <https://en.wikipedia.org/wiki/Permutation#Permutation_group>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::invert_permutation;
+
+fn main(){
+    let perm = [2, 0, 3, 1];
+    assert_eq!(invert_permutation(&perm), vec![1, 3, 0, 2]);
+}
+```
+*/
+pub fn invert_permutation(perm: &[usize]) -> Vec<usize> {
+    let len = perm.len();
+    //len is never a valid position, so it marks positions that were not assigned yet
+    let mut inverse = vec![len; len];
+    for (i, &p) in perm.iter().enumerate() {
+        check_index(p, len);
+        if inverse[p] != len {
+            panic!("The provided sequence is not a valid permutation.");
+        }
+        inverse[p] = i;
+    }
+    inverse
+}
+
+#[inline(always)]
+fn next_permutation_impl<T, F>(arr: &mut [T], mut is_less: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    if arr.len() < 2 {
+        return false;
+    }
+    //find the longest suffix that cannot be increased
+    let mut i = arr.len() - 1;
+    while i > 0 && !is_less(&arr[i - 1], &arr[i]) {
+        i -= 1;
+    }
+    if i == 0 {
+        //this was the last permutation, wrap around to the first one
+        arr.reverse();
+        return false;
+    }
+    //swap the pivot with the smallest greater element of the suffix
+    let mut j = arr.len() - 1;
+    while !is_less(&arr[i - 1], &arr[j]) {
+        j -= 1;
+    }
+    arr.swap(i - 1, j);
+    arr[i..].reverse();
+    true
+}
+
+/**
+Rearranges elements of the slice into the lexicographically next permutation using a custom comparator.
+
+The comparator should return true if the first element is strictly less than the second one.
+Returns true if such permutation exists. Otherwise the slice is rearranged into the first
+permutation (sorted according to the comparator) and false is returned.
+Equal elements are never swapped, so every distinct permutation is generated exactly once.
+
+**More:** <https://en.wikipedia.org/wiki/Permutation#Generation_in_lexicographic_order>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::next_permutation_by;
+
+fn main(){
+    let mut arr = ["a", "b", "c"];
+    assert!(next_permutation_by(&mut arr, |a, b| a<b));
+    assert_eq!(arr, ["a", "c", "b"]);
+}
+```
+*/
+pub fn next_permutation_by<T, F>(arr: &mut [T], is_less: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    next_permutation_impl(arr, is_less)
+}
+
+/**
+Rearranges elements of the slice into the lexicographically next permutation.
+
+The lexicographic order is defined by the provided order of elements. Strict and not strict
+variants of the same order generate the same sequence, because equal elements are never swapped.
+Returns true if the next permutation exists. Otherwise the slice is rearranged into the first
+permutation (sorted in the given order) and false is returned.
+
+**More:** <https://en.wikipedia.org/wiki/Permutation#Generation_in_lexicographic_order>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::next_permutation;
+use algorithm::sort::Order::*;
+
+fn main(){
+    let mut arr = [1, 2, 2];
+    assert!(next_permutation(&mut arr, Increasing));
+    assert_eq!(arr, [2, 1, 2]);
+    assert!(next_permutation(&mut arr, Increasing));
+    assert_eq!(arr, [2, 2, 1]);
+    //there are only 3 distinct permutations
+    assert!(!next_permutation(&mut arr, Increasing));
+    assert_eq!(arr, [1, 2, 2]);
+}
+```
+*/
+pub fn next_permutation<T>(arr: &mut [T], order: Order) -> bool
+    where
+        T: Ord
+{
+    match order {
+        Order::Increasing | Order::NotDecreasing => next_permutation_impl(arr, |a, b| a<b),
+        Order::Decreasing | Order::NotIncreasing => next_permutation_impl(arr, |a, b| a>b),
+    }
+}
+
+/**
+Rearranges elements of the slice into the lexicographically previous permutation using a custom comparator.
+
+The comparator should return true if the first element is strictly less than the second one.
+Returns true if such permutation exists. Otherwise the slice is rearranged into the last
+permutation (sorted in the reversed order) and false is returned.
+
+**More:** <https://en.wikipedia.org/wiki/Permutation#Generation_in_lexicographic_order>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::prev_permutation_by;
+
+fn main(){
+    let mut arr = ["a", "c", "b"];
+    assert!(prev_permutation_by(&mut arr, |a, b| a<b));
+    assert_eq!(arr, ["a", "b", "c"]);
+}
+```
+*/
+pub fn prev_permutation_by<T, F>(arr: &mut [T], mut is_less: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    //previous permutation is the next permutation in the reversed order
+    next_permutation_impl(arr, |a, b| is_less(b, a))
+}
+
+/**
+Rearranges elements of the slice into the lexicographically previous permutation.
+
+The lexicographic order is defined by the provided order of elements.
+Returns true if the previous permutation exists. Otherwise the slice is rearranged into the last
+permutation (sorted in the reversed order) and false is returned.
+
+**More:** <https://en.wikipedia.org/wiki/Permutation#Generation_in_lexicographic_order>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::prev_permutation;
+use algorithm::sort::Order::*;
+
+fn main(){
+    let mut arr = [2, 1, 3];
+    assert!(prev_permutation(&mut arr, Increasing));
+    assert_eq!(arr, [1, 3, 2]);
+}
+```
+*/
+pub fn prev_permutation<T>(arr: &mut [T], order: Order) -> bool
+    where
+        T: Ord
+{
+    match order {
+        Order::Increasing | Order::NotDecreasing => next_permutation_impl(arr, |a, b| a>b),
+        Order::Decreasing | Order::NotIncreasing => next_permutation_impl(arr, |a, b| a<b),
+    }
+}
+
+/**
+Calculates the lexicographic rank of the permutation using the Lehmer code.
+
+Elements of the slice must be distinct. The sorted slice has rank 0, the slice sorted
+in the reversed order has rank n!-1.
+
+Panics if the rank does not fit in ```usize```.
+
+**More:** <https://en.wikipedia.org/wiki/Lehmer_code>
+
+# Complexity
+
+- Processing complexity: O(n²)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::permutation_rank;
+
+fn main(){
+    assert_eq!(permutation_rank(&[0, 1, 2]), 0);
+    assert_eq!(permutation_rank(&['b', 'c', 'a']), 3);
+    assert_eq!(permutation_rank(&[2, 1, 0]), 5);
+}
+```
+*/
+pub fn permutation_rank<T>(arr: &[T]) -> usize
+    where
+        T: Ord
+{
+    let len = arr.len();
+    let mut rank = 0usize;
+    for i in 0..len {
+        //the Lehmer code digit: number of smaller elements to the right
+        let digit = arr[i + 1..].iter().filter(|e| **e < arr[i]).count();
+        rank = rank.checked_mul(len - i)
+            .and_then(|r| r.checked_add(digit))
+            .expect("Permutation rank does not fit in usize.");
+    }
+    rank
+}
+
+/**
+Creates the permutation of numbers ```0..n``` with the given lexicographic rank.
+
+This is the inverse of ```permutation_rank()```.
+
+Panics if ```rank >= n!```.
+
+**More:** <https://en.wikipedia.org/wiki/Lehmer_code>
+
+# Complexity
+
+- Processing complexity: O(n²)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::{permutation_rank, permutation_unrank};
+
+fn main(){
+    let perm = permutation_unrank(3, 3);
+    assert_eq!(perm, vec![1, 2, 0]);
+    assert_eq!(permutation_rank(&perm), 3);
+}
+```
+*/
+pub fn permutation_unrank(n: usize, rank: usize) -> Vec<usize> {
+    //decode the Lehmer code using the factorial number system
+    let mut digits = vec![0usize; n];
+    let mut rest = rank;
+    for base in 1..n + 1 {
+        digits[n - base] = rest % base;
+        rest /= base;
+    }
+    if rest != 0 {
+        panic!("Rank {} is too big for a permutation of {} elements.", rank, n);
+    }
+    let mut available: Vec<usize> = (0..n).collect();
+    digits.into_iter().map(|d| available.remove(d)).collect()
+}
+
+/**
+Iterator over all permutations of the provided elements.
+
+Permutations are generated using Heap's algorithm - every permutation differs from the previous one
+by a single swap. Elements are treated as distinct even if they are equal, so a collection
+of n elements always produces n! permutations.
+
+**More:** <https://en.wikipedia.org/wiki/Heap%27s_algorithm>
+
+# Complexity
+
+- Processing complexity: O(n) per permutation
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::Permutations;
+
+fn main(){
+    let perms: Vec<Vec<i32>> = Permutations::new(vec![1, 2, 3]).collect();
+    assert_eq!(perms.len(), 6);
+    assert_eq!(perms[0], vec![1, 2, 3]);
+    assert_eq!(perms[1], vec![2, 1, 3]);
+}
+```
+*/
+pub struct Permutations<T> where T: Clone {
+    elems: Vec<T>,
+    //the loop counters of Heap's algorithm
+    counters: Vec<usize>,
+    idx: usize,
+    started: bool
+}
+
+impl<T> Permutations<T> where T: Clone {
+    pub fn new<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
+        let elems: Vec<T> = iter.into_iter().collect();
+        let counters = vec![0; elems.len()];
+        Self {
+            elems,
+            counters,
+            idx: 1,
+            started: false
+        }
+    }
+}
+
+impl<T> Iterator for Permutations<T> where T: Clone {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.elems.clone());
+        }
+        while self.idx < self.elems.len() {
+            let i = self.idx;
+            if self.counters[i] < i {
+                if i.is_multiple_of(2) {
+                    self.elems.swap(0, i);
+                } else {
+                    self.elems.swap(self.counters[i], i);
+                }
+                self.counters[i] += 1;
+                self.idx = 1;
+                return Some(self.elems.clone());
+            }
+            self.counters[i] = 0;
+            self.idx += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Order::*;
+
+    #[test]
+    fn apply_empty() {
+        let mut arr: [i32; 0] = [];
+        apply_permutation(&mut arr, &[]);
+    }
+
+    #[test]
+    fn apply_identity() {
+        let mut arr = [1, 2, 3];
+        apply_permutation(&mut arr, &[0, 1, 2]);
+        assert_eq!(arr, [1, 2, 3]);
+    }
+
+    #[test]
+    fn apply_cycles() {
+        //two cycles: (0 3) and (1 2 4)
+        let mut arr = ['a', 'b', 'c', 'd', 'e'];
+        apply_permutation(&mut arr, &[3, 2, 4, 0, 1]);
+        assert_eq!(arr, ['d', 'c', 'e', 'a', 'b']);
+    }
+
+    #[test]
+    fn apply_inverse_restores() {
+        let original = [10, 20, 30, 40, 50, 60];
+        let perm = [4, 0, 5, 1, 3, 2];
+        let mut arr = original;
+        apply_permutation(&mut arr, &perm);
+        apply_permutation(&mut arr, &invert_permutation(&perm));
+        assert_eq!(arr, original);
+    }
+
+    #[should_panic]
+    #[test]
+    fn apply_wrong_len() {
+        let mut arr = [1, 2, 3];
+        apply_permutation(&mut arr, &[0, 1]);
+    }
+
+    #[should_panic]
+    #[test]
+    fn apply_duplicate() {
+        let mut arr = [1, 2];
+        apply_permutation(&mut arr, &[1, 1]);
+    }
+
+    #[should_panic]
+    #[test]
+    fn apply_out_of_range() {
+        let mut arr = [1, 2];
+        apply_permutation(&mut arr, &[0, 2]);
+    }
+
+    #[test]
+    fn invert_simple() {
+        assert_eq!(invert_permutation(&[1, 2, 0]), vec![2, 0, 1]);
+        assert!(invert_permutation(&[]).is_empty());
+    }
+
+    #[should_panic]
+    #[test]
+    fn invert_duplicate() {
+        invert_permutation(&[0, 0]);
+    }
+
+    #[test]
+    fn next_all() {
+        let mut arr = [1, 2, 3];
+        let mut all = vec![arr.to_vec()];
+        while next_permutation(&mut arr, Increasing) {
+            all.push(arr.to_vec());
+        }
+        assert_eq!(all, vec![vec![1, 2, 3], vec![1, 3, 2], vec![2, 1, 3], vec![2, 3, 1], vec![3, 1, 2], vec![3, 2, 1]]);
+        //wrapped around to the first permutation
+        assert_eq!(arr, [1, 2, 3]);
+    }
+
+    #[test]
+    fn next_duplicates() {
+        let mut arr = [1, 1, 2, 2];
+        let mut count = 1;
+        while next_permutation(&mut arr, NotDecreasing) {
+            count += 1;
+        }
+        //4!/(2!*2!)
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    fn next_decreasing() {
+        let mut arr = [3, 1, 2];
+        assert!(next_permutation(&mut arr, Decreasing));
+        assert_eq!(arr, [2, 3, 1]);
+    }
+
+    #[test]
+    fn next_short() {
+        let mut empty: [i32; 0] = [];
+        assert!(!next_permutation(&mut empty, Increasing));
+        let mut single = [1];
+        assert!(!next_permutation(&mut single, Increasing));
+    }
+
+    #[test]
+    fn prev_reverses_next() {
+        let mut arr = [2, 4, 1, 3, 3];
+        let original = arr;
+        assert!(next_permutation(&mut arr, Increasing));
+        assert!(prev_permutation(&mut arr, Increasing));
+        assert_eq!(arr, original);
+    }
+
+    #[test]
+    fn prev_first() {
+        let mut arr = [1, 2, 3];
+        assert!(!prev_permutation(&mut arr, Increasing));
+        assert_eq!(arr, [3, 2, 1]);
+    }
+
+    #[test]
+    fn rank_unrank() {
+        for rank in 0..24 {
+            let perm = permutation_unrank(4, rank);
+            assert_eq!(permutation_rank(&perm), rank);
+        }
+    }
+
+    #[test]
+    fn rank_follows_next() {
+        let mut arr = [0, 1, 2, 3];
+        let mut rank = 0;
+        loop {
+            assert_eq!(permutation_rank(&arr), rank);
+            rank += 1;
+            if !next_permutation(&mut arr, Increasing) {
+                break;
+            }
+        }
+        assert_eq!(rank, 24);
+    }
+
+    #[should_panic]
+    #[test]
+    fn unrank_too_big() {
+        permutation_unrank(3, 6);
+    }
+
+    #[test]
+    fn heap_count() {
+        let mut perms: Vec<Vec<i32>> = Permutations::new(vec![1, 2, 3, 4]).collect();
+        assert_eq!(perms.len(), 24);
+        perms.sort();
+        perms.dedup();
+        assert_eq!(perms.len(), 24);
+    }
+
+    #[test]
+    fn heap_empty() {
+        let perms: Vec<Vec<i32>> = Permutations::new(vec![]).collect();
+        assert_eq!(perms, vec![Vec::<i32>::new()]);
+    }
+}
@@ -1,5 +1,6 @@
-use sort::{shuffle, SortingOrder};
+use sort::{shuffle, shuffle_with_rng, SortingOrder};
 use utils::partition;
+use rand::Rng;
 
 fn quick_sort_impl<T, F>(arr: &mut [T], cmp: F)
     where
@@ -93,6 +94,43 @@ pub fn quick_sort_rand_by<T, F>(arr: &mut [T], cmp: F)
     quick_sort_impl(arr, cmp);
 }
 
+/**
+Sorts a slice using a custom comparator, shuffling it first with the provided random number generator.
+
+This is the same algorithm as ```quick_sort_rand_by()```, but the shuffling step uses the given
+generator, so a seeded generator makes the whole sorting process reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Quicksort>
+
+# Complexity
+
+- Average processing complexity: O(n*log(n))
+- Worst case complexity: O(n²)
+- Memory complexity: O(1)
+
+# Example:
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::sort::quick_sort_rand_by_with_rng;
+use rand::{SeedableRng, XorShiftRng};
+fn main(){
+    let mut arr = [5.0,3.0,1.0,4.0,2.0];
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    quick_sort_rand_by_with_rng(&mut arr, |a,b| a<b, &mut rng);
+    assert_eq!(arr, [1.0, 2.0, 3.0, 4.0, 5.0]);
+}
+```
+*/
+pub fn quick_sort_rand_by_with_rng<T, F, R>(arr: &mut [T], cmp: F, rng: &mut R)
+    where
+        F: FnMut(&T, &T) -> bool + Copy,
+        R: Rng
+{
+    shuffle_with_rng(arr, rng);
+    quick_sort_impl(arr, cmp);
+}
+
 
 /**
 Sorts a slice.
@@ -173,6 +211,44 @@ pub fn quick_sort_rand<T>(arr: &mut [T], order: SortingOrder)
     quick_sort(arr, order);
 }
 
+/**
+Sorts a slice, shuffling it first with the provided random number generator.
+
+This is the same algorithm as ```quick_sort_rand()```, but the shuffling step uses the given
+generator, so a seeded generator makes the whole sorting process reproducible.
+
+**More:** <https://en.wikipedia.org/wiki/Quicksort>
+
+# Complexity
+
+- Average processing complexity: O(n*log(n))
+- Worst case complexity: O(n²)
+- Memory complexity: O(1)
+
+# Example:
+```
+extern crate algorithm;
+extern crate rand;
+use algorithm::sort::quick_sort_rand_with_rng;
+use algorithm::sort::SortingOrder::*;
+use rand::{SeedableRng, XorShiftRng};
+fn main(){
+    let mut arr = [5,3,1,4,2];
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    quick_sort_rand_with_rng(&mut arr, Descending, &mut rng);
+    assert_eq!(arr, [5, 4, 3, 2, 1]);
+}
+```
+*/
+pub fn quick_sort_rand_with_rng<T, R>(arr: &mut [T], order: SortingOrder, rng: &mut R)
+    where
+        T: Ord,
+        R: Rng
+{
+    shuffle_with_rng(arr, rng);
+    quick_sort(arr, order);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +294,13 @@ mod tests {
         let expected = [1,2,3,4,5,6,7,8,9,10];
         assert_eq!(arr, expected);
     }
+
+    #[test]
+    fn seeded() {
+        use rand::{SeedableRng, XorShiftRng};
+        let mut arr = [100,60,30,10,0, 20, 90, 40, 70, 50, 80];
+        let mut rng = XorShiftRng::from_seed([9, 8, 7, 6]);
+        quick_sort_rand_with_rng(&mut arr, Ascending, &mut rng);
+        assert_eq!(arr, [0,10,20,30,40,50,60,70,80,90,100]);
+    }
 }
\ No newline at end of file
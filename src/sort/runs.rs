@@ -0,0 +1,248 @@
+/**
+Iterator over maximal runs of a collection, created by ```runs_by()```.
+*/
+#[derive(Clone, Debug)]
+pub struct Runs<T, I, F> {
+    iter: I,
+    in_run: F,
+    //the last element read from the iterator
+    prev: Option<T>,
+    pos: usize,
+}
+
+impl<T, I, F> Iterator for Runs<T, I, F>
+    where
+        T: Copy,
+        I: Iterator<Item = T>,
+        F: FnMut(T, T) -> bool,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let mut prev = self.prev?;
+        let from = self.pos;
+        loop {
+            self.pos += 1;
+            match self.iter.next() {
+                None => {
+                    self.prev = None;
+                    return Some((from, self.pos));
+                }
+                Some(curr) => {
+                    let continues = (self.in_run)(prev, curr);
+                    prev = curr;
+                    if !continues {
+                        self.prev = Some(curr);
+                        return Some((from, self.pos));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+Iterates over all maximal runs in the provided collection.
+
+A run is a substring in which every pair of neighbours satisfies the predicate. Runs are
+returned as ranges ```(from, to)```, they are not empty and together cover the whole collection.
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::runs_by;
+
+fn main(){
+    let arr = [1, 2, 3, 2, 3, 1];
+    let runs: Vec<(usize, usize)> = runs_by(&arr, |a, b| a < b).collect();
+    assert_eq!(runs, vec![(0, 3), (3, 5), (5, 6)]);
+
+    //runs of consecutive numbers
+    let arr = [4, 5, 6, 10, 11, 20];
+    let runs: Vec<(usize, usize)> = runs_by(&arr, |&a, &b| a + 1 == b).collect();
+    assert_eq!(runs, vec![(0, 3), (3, 5), (5, 6)]);
+}
+```
+*/
+pub fn runs_by<I, T, F>(iter: I, in_run: F) -> Runs<T, I::IntoIter, F>
+    where
+        I: IntoIterator<Item = T>,
+        T: Copy,
+        F: FnMut(T, T) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let prev = iter.next();
+    Runs {
+        iter,
+        in_run,
+        prev,
+        pos: 0,
+    }
+}
+
+/**
+Finds the longest run of elements with equal keys.
+
+Returns the range ```(from, to)``` of the first longest run, ```(0, 0)``` for an empty collection.
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_run_by_key;
+
+fn main(){
+    let arr = [1, 3, 5, 2, 4, 6, 8, 7];
+    //the longest run of even numbers
+    assert_eq!(longest_run_by_key(&arr, |&x| x % 2), (3, 7));
+}
+```
+*/
+pub fn longest_run_by_key<I, T, K, F>(iter: I, mut key: F) -> (usize, usize)
+    where
+        I: IntoIterator<Item = T>,
+        K: PartialEq,
+        F: FnMut(T) -> K,
+{
+    let mut best = (0, 0);
+    let mut from = 0;
+    let mut prev: Option<K> = None;
+    let mut pos = 0;
+    for elem in iter {
+        let curr = key(elem);
+        if prev.as_ref().is_none_or(|p| *p != curr) {
+            from = pos;
+        }
+        pos += 1;
+        if pos - from > best.1 - best.0 {
+            best = (from, pos);
+        }
+        prev = Some(curr);
+    }
+    best
+}
+
+/**
+Finds the longest substring that satisfies a condition using the two pointers technique.
+
+The condition is checked on a state describing the current window of the slice.
+```add``` updates the state when an element enters the window, ```remove``` - when it leaves it.
+The condition must hold for the empty window and for every substring of a window that satisfies it,
+e.g. "sum of non-negative numbers is at most k" or "at most k distinct values" (with ```Counter``` as the state).
+
+Returns the range ```(from, to)``` of the first longest substring.
+
+**More:** <https://www.geeksforgeeks.org/window-sliding-technique/>
+
+# Complexity
+
+- Processing complexity: O(n) calls of each function
+- Memory complexity: O(1) apart from the state
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::longest_substring_where;
+use algorithm::collections::Counter;
+
+fn main(){
+    let arr = [1, 2, 1, 3, 3, 2, 2, 1];
+    //sum at most 8
+    let found = longest_substring_where(&arr, 0, |s, &x| *s += x, |s, &x| *s -= x, |&s| s <= 8);
+    assert_eq!(found, (0, 4));
+    //at most 2 distinct values
+    let found = longest_substring_where(&arr, Counter::<i32>::new(),
+        |c, &x| c.push(x), |c, x| {c.remove_one(x);}, |c| c.len() <= 2);
+    assert_eq!(found, (3, 7));
+}
+```
+*/
+pub fn longest_substring_where<T, S, A, R, P>(arr: &[T], mut state: S, mut add: A, mut remove: R, mut is_valid: P) -> (usize, usize)
+    where
+        A: FnMut(&mut S, &T),
+        R: FnMut(&mut S, &T),
+        P: FnMut(&S) -> bool,
+{
+    let mut best = (0, 0);
+    let mut from = 0;
+    for (to, elem) in arr.iter().enumerate() {
+        add(&mut state, elem);
+        //shrink the window from the left until it is valid again
+        while from <= to && !is_valid(&state) {
+            remove(&mut state, &arr[from]);
+            from += 1;
+        }
+        if to + 1 - from > best.1 - best.0 {
+            best = (from, to + 1);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use collections::Counter;
+
+    #[test]
+    fn runs() {
+        let empty: [i32; 0] = [];
+        assert_eq!(runs_by(&empty, |a, b| a < b).count(), 0);
+        assert_eq!(runs_by(&[7], |a, b| a < b).collect::<Vec<_>>(), vec![(0, 1)]);
+        let arr = [1, 1, 2, 2, 2, 3];
+        assert_eq!(runs_by(&arr, |a, b| a == b).collect::<Vec<_>>(), vec![(0, 2), (2, 5), (5, 6)]);
+        assert_eq!(runs_by(&arr, |a, b| a <= b).collect::<Vec<_>>(), vec![(0, 6)]);
+    }
+
+    #[test]
+    fn runs_match_longest_ordered_substring() {
+        use sort::longest_ordered_substring_idx_by;
+        let arr = [5, 4, 3, 4, 5, 4, 3, 4, 5, 6, 3, 2, 1];
+        let longest = runs_by(&arr, |a, b| a < b).fold((0, 0), |best, run| {
+            if run.1 - run.0 > best.1 - best.0 { run } else { best }
+        });
+        assert_eq!(longest, longest_ordered_substring_idx_by(&arr, |a, b| a < b));
+    }
+
+    #[test]
+    fn longest_run() {
+        let empty: [i32; 0] = [];
+        assert_eq!(longest_run_by_key(&empty, |&x| x), (0, 0));
+        assert_eq!(longest_run_by_key("aabbbcccdd".chars(), |c| c), (2, 5));
+        assert_eq!(longest_run_by_key(&[1, 2, 3], |&x| x), (0, 1));
+    }
+
+    #[test]
+    fn window_matches_naive() {
+        let arr = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9];
+        for k in 0..6 {
+            let found = longest_substring_where(&arr, Counter::<i32>::new(),
+                |c, &x| c.push(x), |c, x| { c.remove_one(x); }, |c| c.len() <= k);
+            let mut best = (0, 0);
+            for from in 0..arr.len() {
+                for to in from + 1..arr.len() + 1 {
+                    let distinct: Counter<i32> = arr[from..to].iter().collect();
+                    if distinct.len() <= k && to - from > best.1 - best.0 {
+                        best = (from, to);
+                    }
+                }
+            }
+            assert_eq!(found, best);
+        }
+    }
+
+    #[test]
+    fn window_nothing_valid() {
+        let arr = [5, 6, 7];
+        assert_eq!(longest_substring_where(&arr, 0, |s, &x| *s += x, |s, &x| *s -= x, |&s| s <= 4), (0, 0));
+    }
+}
@@ -27,4 +27,143 @@ pub fn shuffle<T>(arr: &mut [T]) {
     //it turns out that rand already has an efficient implementation, so just forward this call
     //so that users of this crate have access to all functions without the need for external sources
     thread_rng().shuffle(arr)
-}
\ No newline at end of file
+}
+
+/**
+    Shuffles elements in the slice using the provided random number generator.
+
+    Using a seeded generator makes the result reproducible.
+
+    # Complexity
+    - Processing complexity: O(n)
+    - Memory complexity: O(1)
+
+    # Example
+    ```
+    extern crate algorithm;
+    extern crate rand;
+    use algorithm::sort::shuffle_with_rng;
+    use rand::{SeedableRng, XorShiftRng};
+
+    fn main(){
+        let mut a = [1,2,3,4,5];
+        let mut b = [1,2,3,4,5];
+        shuffle_with_rng(&mut a, &mut XorShiftRng::from_seed([1, 2, 3, 4]));
+        shuffle_with_rng(&mut b, &mut XorShiftRng::from_seed([1, 2, 3, 4]));
+        //the same seed gives the same order
+        assert_eq!(a, b);
+    }
+    ```
+*/
+pub fn shuffle_with_rng<T, R>(arr: &mut [T], rng: &mut R)
+    where R: Rng
+{
+    rng.shuffle(arr)
+}
+
+/**
+    Randomly chooses k elements of the slice.
+
+    This is a partial Fisher-Yates shuffle: chosen elements are moved to the beginning
+    of the slice in a random order and the returned subslice contains them.
+    The remaining elements stay at the end of the slice in an unspecified order.
+    If k is greater than the length of the slice, the whole slice gets shuffled.
+
+    **More:** <https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle>
+
+    # Complexity
+    - Processing complexity: O(k)
+    - Memory complexity: O(1)
+
+    # Example
+    ```
+    extern crate algorithm;
+    use algorithm::sort::choose_multiple;
+
+    fn main(){
+        let mut arr = [1,2,3,4,5,6,7,8,9,10];
+        let chosen = choose_multiple(&mut arr, 3);
+        assert_eq!(chosen.len(), 3);
+    }
+    ```
+*/
+pub fn choose_multiple<T>(arr: &mut [T], k: usize) -> &mut [T] {
+    choose_multiple_with_rng(arr, k, &mut thread_rng())
+}
+
+/**
+    Randomly chooses k elements of the slice using the provided random number generator.
+
+    This is a partial Fisher-Yates shuffle: chosen elements are moved to the beginning
+    of the slice in a random order and the returned subslice contains them.
+    The remaining elements stay at the end of the slice in an unspecified order.
+    If k is greater than the length of the slice, the whole slice gets shuffled.
+
+    **More:** <https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle>
+
+    # Complexity
+    - Processing complexity: O(k)
+    - Memory complexity: O(1)
+
+    # Example
+    ```
+    extern crate algorithm;
+    extern crate rand;
+    use algorithm::sort::choose_multiple_with_rng;
+    use rand::{SeedableRng, XorShiftRng};
+
+    fn main(){
+        let mut arr = ['a', 'b', 'c', 'd', 'e'];
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let chosen = choose_multiple_with_rng(&mut arr, 2, &mut rng);
+        assert_eq!(chosen.len(), 2);
+    }
+    ```
+*/
+pub fn choose_multiple_with_rng<'a, T, R>(arr: &'a mut [T], k: usize, rng: &mut R) -> &'a mut [T]
+    where R: Rng
+{
+    let k = k.min(arr.len());
+    for i in 0..k {
+        let j = rng.gen_range(i, arr.len());
+        arr.swap(i, j);
+    }
+    &mut arr[..k]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, XorShiftRng};
+
+    #[test]
+    fn seeded_shuffle() {
+        let mut a: Vec<i32> = (0..20).collect();
+        let mut b = a.clone();
+        shuffle_with_rng(&mut a, &mut XorShiftRng::from_seed([5, 6, 7, 8]));
+        shuffle_with_rng(&mut b, &mut XorShiftRng::from_seed([5, 6, 7, 8]));
+        assert_eq!(a, b);
+        a.sort();
+        assert_eq!(a, (0..20).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn choose_distinct() {
+        let mut arr: Vec<i32> = (0..10).collect();
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let mut chosen = choose_multiple_with_rng(&mut arr, 4, &mut rng).to_vec();
+        chosen.sort();
+        chosen.dedup();
+        assert_eq!(chosen.len(), 4);
+        arr.sort();
+        assert_eq!(arr, (0..10).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn choose_too_many() {
+        let mut arr = [1, 2, 3];
+        assert_eq!(choose_multiple(&mut arr, 5).len(), 3);
+        let mut empty: [i32; 0] = [];
+        assert!(choose_multiple(&mut empty, 2).is_empty());
+    }
+}
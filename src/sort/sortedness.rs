@@ -0,0 +1,413 @@
+use std::iter::{IntoIterator, Iterator};
+use std::mem::swap;
+use sort::{Order, longest_ordered_subsequence_by};
+use utils::PairIterator;
+
+//bottom-up merge sort that counts pairs which are not ordered
+//it works on references, so elements do not need to be cloned
+#[inline(always)]
+fn count_inversions_impl<T, F>(arr: &[T], mut is_ordered: F) -> usize
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    let len = arr.len();
+    let mut curr: Vec<&T> = arr.iter().collect();
+    let mut next: Vec<&T> = curr.clone();
+    let mut inversions = 0usize;
+    let mut width = 1usize;
+    while width < len {
+        let mut lo = 0usize;
+        while lo < len {
+            let mid = (lo + width).min(len);
+            let hi = (lo + 2 * width).min(len);
+            let (mut i, mut j, mut k) = (lo, mid, lo);
+            while i < mid && j < hi {
+                if is_ordered(curr[i], curr[j]) {
+                    next[k] = curr[i];
+                    i += 1;
+                } else {
+                    //both halves are sorted, so curr[j] is not ordered
+                    //with any of the remaining elements of the left half
+                    inversions += mid - i;
+                    next[k] = curr[j];
+                    j += 1;
+                }
+                k += 1;
+            }
+            next[k..k + mid - i].copy_from_slice(&curr[i..mid]);
+            k += mid - i;
+            next[k..hi].copy_from_slice(&curr[j..hi]);
+            lo = hi;
+        }
+        swap(&mut curr, &mut next);
+        width *= 2;
+    }
+    inversions
+}
+
+/**
+Counts inversions in the slice using a custom comparator.
+
+An inversion is a pair of indexes ```i < j``` such that elements ```arr[i]``` and ```arr[j]```
+are not ordered. The comparator must define a transitive order, such as ```a<b``` or ```a>=b```.
+The number of inversions measures how far the slice is from being sorted: it is 0 for an
+ordered slice and n*(n-1)/2 for a slice ordered in the opposite direction.
+
+**More:** <https://en.wikipedia.org/wiki/Inversion_(discrete_mathematics)>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_inversions_by;
+
+fn main(){
+    let arr = [3.0, 1.0, 2.0];
+    //inversions are (3.0, 1.0) and (3.0, 2.0)
+    assert_eq!(count_inversions_by(&arr, |a, b| a<b), 2);
+}
+```
+*/
+pub fn count_inversions_by<T, F>(arr: &[T], is_ordered: F) -> usize
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    count_inversions_impl(arr, is_ordered)
+}
+
+/**
+Counts inversions in the slice.
+
+An inversion is a pair of indexes ```i < j``` such that elements ```arr[i]``` and ```arr[j]```
+do not follow the given order. The number of inversions measures how far the slice is from
+being sorted: it is 0 for an ordered slice and n*(n-1)/2 for a slice ordered in the opposite
+direction.
+
+**More:** <https://en.wikipedia.org/wiki/Inversion_(discrete_mathematics)>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_inversions;
+use algorithm::sort::Order::*;
+
+fn main(){
+    let arr = [2, 4, 1, 3, 3];
+    assert_eq!(count_inversions(&arr, NotDecreasing), 4);
+    //pair (3, 3) is not strictly increasing
+    assert_eq!(count_inversions(&arr, Increasing), 5);
+}
+```
+*/
+pub fn count_inversions<T>(arr: &[T], order: Order) -> usize
+    where
+        T: PartialOrd
+{
+    match order {
+        Order::Increasing => count_inversions_impl(arr, |a, b| a<b),
+        Order::Decreasing => count_inversions_impl(arr, |a, b| a>b),
+        Order::NotIncreasing => count_inversions_impl(arr, |a, b| a>=b),
+        Order::NotDecreasing => count_inversions_impl(arr, |a, b| a<=b),
+    }
+}
+
+/**
+Calculates Kendall tau distance between two rankings.
+
+Both slices assign a rank (or a score) to the same elements: ```a[i]``` and ```b[i]``` describe
+the element ```i```. The distance is the number of pairs of elements that are ordered differently
+by the two rankings. Pairs that are equal in any of the rankings are not counted.
+
+Panics if the slices have different lengths.
+
+**More:** <https://en.wikipedia.org/wiki/Kendall_tau_distance>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::kendall_tau_distance;
+
+fn main(){
+    let a = [1, 2, 3, 4, 5];
+    let b = [3, 4, 1, 2, 5];
+    assert_eq!(kendall_tau_distance(&a, &b), 4);
+}
+```
+*/
+pub fn kendall_tau_distance<T>(a: &[T], b: &[T]) -> usize
+    where
+        T: Ord
+{
+    if a.len() != b.len() {
+        panic!("Rankings have different lengths.");
+    }
+    //order elements by the first ranking, breaking ties with the second one
+    let mut indexes: Vec<usize> = (0..a.len()).collect();
+    indexes.sort_unstable_by(|&i, &j| a[i].cmp(&a[j]).then_with(|| b[i].cmp(&b[j])));
+    //now every pair that is not ordered by the second ranking is discordant
+    let second: Vec<&T> = indexes.iter().map(|&i| &b[i]).collect();
+    count_inversions_impl(&second, |x, y| x<=y)
+}
+
+/**
+Counts maximal ordered runs in the provided collection using a custom comparator.
+
+A run is a substring in which every pair of neighbours is ordered.
+An ordered collection consists of a single run, an empty collection has no runs.
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_runs_by;
+
+fn main(){
+    let arr = [1.0, 2.0, 0.5, 0.7, 0.1];
+    //runs are [1.0, 2.0], [0.5, 0.7] and [0.1]
+    assert_eq!(count_runs_by(&arr, |&a, &b| a<b), 3);
+}
+```
+*/
+pub fn count_runs_by<I, T, F>(iter: I, mut is_ordered: F) -> usize
+    where
+        I: IntoIterator<Item = T>,
+        T: Copy,
+        F: FnMut(T, T) -> bool,
+{
+    match PairIterator::try_new(iter) {
+        None => 0,
+        Some(iter) => 1 + iter.filter(|&(a, b)| !is_ordered(a, b)).count()
+    }
+}
+
+/**
+Counts maximal ordered runs in the provided collection.
+
+A run is a substring that follows the given order.
+An ordered collection consists of a single run, an empty collection has no runs.
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::count_runs;
+use algorithm::sort::Order::*;
+
+fn main(){
+    let arr = [1, 2, 2, 0, 3];
+    //ascending runs are [1, 2], [2], [0, 3]
+    assert_eq!(count_runs(&arr, Increasing), 3);
+    //not decreasing runs are [1, 2, 2], [0, 3]
+    assert_eq!(count_runs(&arr, NotDecreasing), 2);
+}
+```
+*/
+pub fn count_runs<I, T>(iter: I, order: Order) -> usize
+    where
+        I: IntoIterator<Item = T>,
+        T: Copy + PartialOrd,
+{
+    match order {
+        Order::Increasing => count_runs_by(iter, |a, b| a<b),
+        Order::Decreasing => count_runs_by(iter, |a, b| a>b),
+        Order::NotIncreasing => count_runs_by(iter, |a, b| a>=b),
+        Order::NotDecreasing => count_runs_by(iter, |a, b| a<=b),
+    }
+}
+
+/**
+Calculates the minimum number of elements that need to be removed to make the slice ordered,
+using a custom comparator.
+
+The remaining elements form the longest ordered subsequence.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::min_removals_to_order_by;
+
+fn main(){
+    let arr = [1.0, 5.0, 2.0, 3.0, 0.0];
+    //removing 5.0 and 0.0 leaves [1.0, 2.0, 3.0]
+    assert_eq!(min_removals_to_order_by(&arr, |a, b| a<b), 2);
+}
+```
+*/
+pub fn min_removals_to_order_by<T, F>(arr: &[T], is_ordered: F) -> usize
+    where
+        F: FnMut(&T, &T) -> bool
+{
+    arr.len() - longest_ordered_subsequence_by(arr, is_ordered).len()
+}
+
+/**
+Calculates the minimum number of elements that need to be removed to make the slice ordered.
+
+The remaining elements form the longest ordered subsequence.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_increasing_subsequence>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::sort::min_removals_to_order;
+use algorithm::sort::Order::*;
+
+fn main(){
+    let arr = [3, 4, -1, 5, 8, 2, 3, 12, 7, 9, 10];
+    //the longest increasing subsequence is [-1, 2, 3, 7, 9, 10]
+    assert_eq!(min_removals_to_order(&arr, Increasing), 5);
+}
+```
+*/
+pub fn min_removals_to_order<T>(arr: &[T], order: Order) -> usize
+    where
+        T: PartialOrd
+{
+    match order {
+        Order::Increasing => min_removals_to_order_by(arr, |a, b| a<b),
+        Order::Decreasing => min_removals_to_order_by(arr, |a, b| a>b),
+        Order::NotIncreasing => min_removals_to_order_by(arr, |a, b| a>=b),
+        Order::NotDecreasing => min_removals_to_order_by(arr, |a, b| a<=b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Order::*;
+
+    fn brute_inversions(arr: &[i32], is_ordered: fn(&i32, &i32) -> bool) -> usize {
+        let mut count = 0;
+        for i in 0..arr.len() {
+            for j in i + 1..arr.len() {
+                if !is_ordered(&arr[i], &arr[j]) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn inversions_empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(count_inversions(&arr, Increasing), 0);
+    }
+
+    #[test]
+    fn inversions_sorted() {
+        let arr = [1, 2, 3, 4, 5];
+        assert_eq!(count_inversions(&arr, Increasing), 0);
+        assert_eq!(count_inversions(&arr, Decreasing), 10);
+    }
+
+    #[test]
+    fn inversions_equal() {
+        let arr = [7, 7, 7];
+        assert_eq!(count_inversions(&arr, Increasing), 3);
+        assert_eq!(count_inversions(&arr, NotDecreasing), 0);
+        assert_eq!(count_inversions(&arr, NotIncreasing), 0);
+    }
+
+    #[test]
+    fn inversions_brute() {
+        let arr = [5, 3, 9, 3, 1, 0, 7, 7, 2, 8, 6, 4, 5];
+        assert_eq!(count_inversions(&arr, Increasing), brute_inversions(&arr, |a, b| a<b));
+        assert_eq!(count_inversions(&arr, Decreasing), brute_inversions(&arr, |a, b| a>b));
+        assert_eq!(count_inversions(&arr, NotIncreasing), brute_inversions(&arr, |a, b| a>=b));
+        assert_eq!(count_inversions(&arr, NotDecreasing), brute_inversions(&arr, |a, b| a<=b));
+    }
+
+    #[test]
+    fn kendall_identical() {
+        let a = [4, 1, 3, 2];
+        assert_eq!(kendall_tau_distance(&a, &a), 0);
+    }
+
+    #[test]
+    fn kendall_reversed() {
+        let a = [1, 2, 3, 4];
+        let b = [4, 3, 2, 1];
+        assert_eq!(kendall_tau_distance(&a, &b), 6);
+    }
+
+    #[test]
+    fn kendall_ties() {
+        //pairs (0, 1) and (2, 3) are tied in one of the rankings
+        let a = [1, 1, 2, 3];
+        let b = [2, 1, 4, 4];
+        assert_eq!(kendall_tau_distance(&a, &b), 0);
+        let c = [3, 1, 2, 2];
+        assert_eq!(kendall_tau_distance(&a, &c), 2);
+    }
+
+    #[should_panic]
+    #[test]
+    fn kendall_wrong_len() {
+        kendall_tau_distance(&[1, 2], &[1]);
+    }
+
+    #[test]
+    fn runs_empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(count_runs(arr, Increasing), 0);
+    }
+
+    #[test]
+    fn runs_single() {
+        assert_eq!(count_runs([5], Decreasing), 1);
+    }
+
+    #[test]
+    fn runs_multiple() {
+        let arr = [1, 3, 5, 2, 4, 4, 0];
+        assert_eq!(count_runs(arr, Increasing), 4);
+        assert_eq!(count_runs(arr, NotDecreasing), 3);
+        assert_eq!(count_runs(arr, Decreasing), 5);
+    }
+
+    #[test]
+    fn removals() {
+        let arr = [5, 1, 2, 2, 3, 0];
+        assert_eq!(min_removals_to_order(&arr, Increasing), 3);
+        assert_eq!(min_removals_to_order(&arr, NotDecreasing), 2);
+        assert_eq!(min_removals_to_order(&arr, Decreasing), 3);
+        let empty: [i32; 0] = [];
+        assert_eq!(min_removals_to_order(&empty, Increasing), 0);
+    }
+}
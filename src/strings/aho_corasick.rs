@@ -0,0 +1,507 @@
+use collections::Counter;
+use fnv::FnvHashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/**
+Selects which matches are reported by ```AhoCorasick```.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MatchKind {
+    ///All matches of all patterns are reported, even if they overlap.
+    Overlapping,
+    /**
+    Non-overlapping matches are reported. Of all matches starting at the same position
+    the longest one is chosen, then the search continues after its end.
+    */
+    LeftmostLongest,
+}
+
+/**
+A match found by ```AhoCorasick```.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Match {
+    ///Index of the matched pattern, in the order in which patterns were provided.
+    pub pattern: usize,
+    ///Position of the first element of the match.
+    pub start: usize,
+    ///Position after the last element of the match.
+    pub end: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    children: FnvHashMap<T, usize>,
+    //the longest proper suffix that is also a prefix of some pattern
+    fail: usize,
+    //the longest proper suffix that is a whole pattern
+    dict: Option<usize>,
+    depth: usize,
+    //patterns ending in this node
+    outputs: Vec<usize>,
+}
+
+impl<T> Node<T> where T: Hash + Eq {
+    fn new(depth: usize) -> Self {
+        Self {
+            children: FnvHashMap::default(),
+            fail: 0,
+            dict: None,
+            depth,
+            outputs: Vec::new(),
+        }
+    }
+}
+
+const ROOT: usize = 0;
+
+/**
+Automaton that finds occurrences of many patterns at once.
+
+The automaton is built once from all patterns and then it finds all of them in a single pass over
+the text, no matter how many patterns there are. Patterns and the text can be sequences of any
+hashable elements: bytes, chars or tokens. The text is consumed as an iterator, so it does not
+need to be stored in the memory - this allows scanning streams such as log files.
+Empty patterns are ignored, they never match.
+
+**More:** <https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm>
+
+# Complexity
+
+- Creation complexity: O(m)
+- Search complexity: O(n + k)
+- Memory complexity: O(m)
+
+where m - total length of patterns, n - length of the text, k - number of matches.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::{AhoCorasick, Match, MatchKind};
+
+fn main(){
+    let ac = AhoCorasick::new(vec!["he".chars(), "she".chars(), "hers".chars()]);
+    let found: Vec<Match> = ac.find_iter("ushers".chars()).collect();
+    assert_eq!(found, vec![
+        Match { pattern: 1, start: 1, end: 4 },
+        Match { pattern: 0, start: 2, end: 4 },
+        Match { pattern: 2, start: 2, end: 6 },
+    ]);
+
+    let ac = AhoCorasick::with_match_kind(vec!["he".chars(), "hers".chars()], MatchKind::LeftmostLongest);
+    let found: Vec<usize> = ac.find_iter("he hers".chars()).map(|m| m.pattern).collect();
+    assert_eq!(found, vec![0, 1]);
+
+    //frequency of every pattern
+    let counts = ac.count_matches("he said hers, she said his".chars());
+    assert_eq!(counts[&0], 2);
+    assert_eq!(counts[&1], 1);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct AhoCorasick<T> {
+    nodes: Vec<Node<T>>,
+    pattern_lens: Vec<usize>,
+    max_len: usize,
+    kind: MatchKind,
+}
+
+impl<T> AhoCorasick<T> where T: Hash + Eq + Clone {
+    /**
+    Creates a new automaton that reports overlapping matches of the given patterns.
+
+    **Complexity:** O(m)
+    */
+    pub fn new<I, P>(patterns: I) -> Self
+        where
+            I: IntoIterator<Item = P>,
+            P: IntoIterator<Item = T>,
+    {
+        Self::with_match_kind(patterns, MatchKind::Overlapping)
+    }
+
+    /**
+    Creates a new automaton that reports matches of the given patterns in the given way.
+
+    **Complexity:** O(m)
+    */
+    pub fn with_match_kind<I, P>(patterns: I, kind: MatchKind) -> Self
+        where
+            I: IntoIterator<Item = P>,
+            P: IntoIterator<Item = T>,
+    {
+        let mut nodes = vec![Node::new(0)];
+        let mut pattern_lens = Vec::new();
+        for (idx, pattern) in patterns.into_iter().enumerate() {
+            let mut curr = ROOT;
+            for elem in pattern {
+                let next = nodes.len();
+                let depth = nodes[curr].depth + 1;
+                curr = match nodes[curr].children.get(&elem).cloned() {
+                    Some(child) => child,
+                    None => {
+                        nodes[curr].children.insert(elem, next);
+                        nodes.push(Node::new(depth));
+                        next
+                    }
+                };
+            }
+            if curr != ROOT {
+                nodes[curr].outputs.push(idx);
+            }
+            pattern_lens.push(nodes[curr].depth);
+        }
+        Self::link(&mut nodes);
+        Self {
+            nodes,
+            max_len: pattern_lens.iter().cloned().max().unwrap_or(0),
+            pattern_lens,
+            kind,
+        }
+    }
+
+    //calculates failure and dictionary links in BFS order
+    fn link(nodes: &mut [Node<T>]) {
+        let mut queue: VecDeque<usize> = nodes[ROOT].children.values().cloned().collect();
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(T, usize)> = nodes[node].children.iter()
+                .map(|(elem, &child)| (elem.clone(), child))
+                .collect();
+            for (elem, child) in children {
+                let mut fail = nodes[node].fail;
+                let fail = loop {
+                    if let Some(&next) = nodes[fail].children.get(&elem) {
+                        break next;
+                    }
+                    if fail == ROOT {
+                        break ROOT;
+                    }
+                    fail = nodes[fail].fail;
+                };
+                nodes[child].fail = fail;
+                nodes[child].dict = if nodes[fail].outputs.is_empty() { nodes[fail].dict } else { Some(fail) };
+                queue.push_back(child);
+            }
+        }
+    }
+
+    //moves the automaton from the given state by the given element
+    #[inline(always)]
+    fn next_state(&self, mut state: usize, elem: &T) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[state].children.get(elem) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.nodes[state].fail;
+        }
+    }
+
+    /**
+    Returns the number of patterns.
+    */
+    pub fn patterns_len(&self) -> usize {
+        self.pattern_lens.len()
+    }
+
+    /**
+    Returns the way matches are reported.
+    */
+    pub fn match_kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    /**
+    Returns an iterator over matches of patterns in the given text.
+
+    Overlapping matches are reported in the order of their ends, matches with the same end
+    are reported from the longest. Leftmost-longest matches are reported in the order of their
+    starts. The text is consumed lazily.
+    */
+    pub fn find_iter<I>(&self, text: I) -> FindIter<'_, T, I::IntoIter>
+        where
+            I: IntoIterator<Item = T>,
+    {
+        FindIter {
+            ac: self,
+            text: text.into_iter(),
+            state: ROOT,
+            pos: 0,
+            found: VecDeque::new(),
+            history: VecDeque::new(),
+            replay: VecDeque::new(),
+            candidate: None,
+        }
+    }
+
+    /**
+    Checks if any of the patterns occurs in the given text.
+
+    The text is consumed only up to the first match.
+    */
+    pub fn is_match<I>(&self, text: I) -> bool
+        where
+            I: IntoIterator<Item = T>,
+    {
+        //overlapping matches are reported as soon as they end
+        let mut state = ROOT;
+        for elem in text {
+            state = self.next_state(state, &elem);
+            if !self.nodes[state].outputs.is_empty() || self.nodes[state].dict.is_some() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /**
+    Counts matches of every pattern in the given text.
+
+    Keys of the counter are indexes of patterns.
+    */
+    pub fn count_matches<I>(&self, text: I) -> Counter<usize>
+        where
+            I: IntoIterator<Item = T>,
+    {
+        self.find_iter(text).map(|m| m.pattern).collect()
+    }
+}
+
+impl<T, P> FromIterator<P> for AhoCorasick<T>
+    where
+        T: Hash + Eq + Clone,
+        P: IntoIterator<Item = T>,
+{
+    ///Creates a new automaton that reports overlapping matches of the given patterns.
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/**
+Iterator over matches of ```AhoCorasick```, created by ```AhoCorasick::find_iter()```.
+*/
+#[derive(Clone, Debug)]
+pub struct FindIter<'a, T: 'a, I> {
+    ac: &'a AhoCorasick<T>,
+    text: I,
+    state: usize,
+    //number of elements consumed by the automaton
+    pos: usize,
+    //matches ready to be returned
+    found: VecDeque<Match>,
+    //recently consumed elements, needed to restart the search after a leftmost-longest match
+    history: VecDeque<T>,
+    //elements that need to be consumed again after a restart
+    replay: VecDeque<T>,
+    //the best leftmost-longest match that might still be beaten
+    candidate: Option<Match>,
+}
+
+impl<'a, T, I> FindIter<'a, T, I>
+    where
+        T: Hash + Eq + Clone,
+        I: Iterator<Item = T>,
+{
+    //consumes one element, returns false at the end of the text
+    fn step(&mut self) -> bool {
+        let elem = match self.replay.pop_front() {
+            Some(elem) => elem,
+            None => match self.text.next() {
+                Some(elem) => elem,
+                None => return false,
+            },
+        };
+        let ac = self.ac;
+        self.state = ac.next_state(self.state, &elem);
+        self.pos += 1;
+        //report matches from the longest, following dictionary links
+        let mut node = if ac.nodes[self.state].outputs.is_empty() { ac.nodes[self.state].dict } else { Some(self.state) };
+        match ac.kind {
+            MatchKind::Overlapping => {
+                while let Some(curr) = node {
+                    for &pattern in &ac.nodes[curr].outputs {
+                        self.found.push_back(Match { pattern, start: self.pos - ac.nodes[curr].depth, end: self.pos });
+                    }
+                    node = ac.nodes[curr].dict;
+                }
+            }
+            MatchKind::LeftmostLongest => {
+                self.history.push_back(elem);
+                if self.history.len() > ac.max_len + 1 {
+                    self.history.pop_front();
+                }
+                //the longest match ending here has the leftmost start, duplicated patterns report the first one
+                if let Some(curr) = node {
+                    let start = self.pos - ac.nodes[curr].depth;
+                    let better = match self.candidate {
+                        None => true,
+                        Some(cand) => start < cand.start || (start == cand.start && self.pos > cand.end),
+                    };
+                    if better {
+                        self.candidate = Some(Match { pattern: ac.nodes[curr].outputs[0], start, end: self.pos });
+                    }
+                }
+                //future matches start at pos - depth or later, so they cannot beat the candidate
+                if let Some(cand) = self.candidate {
+                    if self.pos - ac.nodes[self.state].depth > cand.start {
+                        self.accept_candidate();
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    //reports the candidate and restarts the search after its end
+    fn accept_candidate(&mut self) {
+        if let Some(cand) = self.candidate.take() {
+            self.found.push_back(cand);
+            let history_start = self.pos - self.history.len();
+            let mut rest = self.history.split_off(cand.end - history_start);
+            rest.extend(self.replay.drain(..));
+            self.replay = rest;
+            self.history.clear();
+            self.state = ROOT;
+            self.pos = cand.end;
+        }
+    }
+}
+
+impl<'a, T, I> Iterator for FindIter<'a, T, I>
+    where
+        T: Hash + Eq + Clone,
+        I: Iterator<Item = T>,
+{
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            if let Some(found) = self.found.pop_front() {
+                return Some(found);
+            }
+            if !self.step() {
+                //the end of the text, the candidate cannot be beaten any more
+                self.candidate?;
+                self.accept_candidate();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_overlapping(patterns: &[&str], text: &str) -> Vec<Match> {
+        let text = text.as_bytes();
+        let mut result = Vec::new();
+        for end in 1..text.len() + 1 {
+            let mut at_end: Vec<Match> = patterns.iter().enumerate()
+                .filter(|&(_, p)| !p.is_empty() && text[..end].ends_with(p.as_bytes()))
+                .map(|(pattern, p)| Match { pattern, start: end - p.len(), end })
+                .collect();
+            at_end.sort_by_key(|m| m.start);
+            result.extend(at_end);
+        }
+        result
+    }
+
+    fn naive_leftmost_longest(patterns: &[&str], text: &str) -> Vec<Match> {
+        let text = text.as_bytes();
+        let mut result = Vec::new();
+        let mut start = 0;
+        while start < text.len() {
+            let best = patterns.iter().enumerate()
+                .filter(|&(_, p)| !p.is_empty() && text[start..].starts_with(p.as_bytes()))
+                .max_by_key(|&(idx, p)| (p.len(), -(idx as isize)));
+            match best {
+                Some((pattern, p)) => {
+                    result.push(Match { pattern, start, end: start + p.len() });
+                    start += p.len();
+                }
+                None => start += 1,
+            }
+        }
+        result
+    }
+
+    fn build(patterns: &[&str], kind: MatchKind) -> AhoCorasick<u8> {
+        AhoCorasick::with_match_kind(patterns.iter().map(|p| p.bytes()), kind)
+    }
+
+    const PATTERNS: [&str; 9] = ["a", "ab", "bab", "bc", "bca", "c", "caa", "abcabd", "d"];
+    const TEXTS: [&str; 5] = ["abccab", "abcabcabd", "", "xyz", "bcaabababcabdcaa"];
+
+    #[test]
+    fn overlapping_matches_naive() {
+        let ac = build(&PATTERNS, MatchKind::Overlapping);
+        for text in &TEXTS {
+            assert_eq!(ac.find_iter(text.bytes()).collect::<Vec<_>>(), naive_overlapping(&PATTERNS, text));
+        }
+    }
+
+    #[test]
+    fn leftmost_longest_matches_naive() {
+        let ac = build(&PATTERNS, MatchKind::LeftmostLongest);
+        for text in &TEXTS {
+            assert_eq!(ac.find_iter(text.bytes()).collect::<Vec<_>>(), naive_leftmost_longest(&PATTERNS, text));
+        }
+    }
+
+    #[test]
+    fn leftmost_longest_restart() {
+        //"ab" must be reported before "c", although "abcX" is still possible when "c" ends
+        let patterns = ["ab", "c", "abcx"];
+        let ac = build(&patterns, MatchKind::LeftmostLongest);
+        for text in &["abcy", "abc", "abcx", "aabcabcxc"] {
+            assert_eq!(ac.find_iter(text.bytes()).collect::<Vec<_>>(), naive_leftmost_longest(&patterns, text));
+        }
+    }
+
+    #[test]
+    fn all_short_texts() {
+        let patterns = ["a", "ab", "abc", "bcab", "ca", "cc", "abcabc", "bb"];
+        let overlapping = build(&patterns, MatchKind::Overlapping);
+        let leftmost = build(&patterns, MatchKind::LeftmostLongest);
+        let mut texts = vec![String::new()];
+        for _ in 0..7 {
+            texts = texts.iter().flat_map(|t| "abc".chars().map(move |c| format!("{}{}", t, c))).collect();
+            for text in &texts {
+                assert_eq!(overlapping.find_iter(text.bytes()).collect::<Vec<_>>(), naive_overlapping(&patterns, text));
+                assert_eq!(leftmost.find_iter(text.bytes()).collect::<Vec<_>>(), naive_leftmost_longest(&patterns, text));
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates_and_empty() {
+        let patterns = ["ab", "", "ab"];
+        let ac = build(&patterns, MatchKind::Overlapping);
+        assert_eq!(ac.patterns_len(), 3);
+        assert_eq!(ac.find_iter("xab".bytes()).map(|m| m.pattern).collect::<Vec<_>>(), vec![0, 2]);
+        let ac = build(&patterns, MatchKind::LeftmostLongest);
+        assert_eq!(ac.find_iter("xab".bytes()).map(|m| m.pattern).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn is_match() {
+        let ac: AhoCorasick<char> = vec!["error".chars(), "fatal".chars()].into_iter().collect();
+        assert!(ac.is_match("an error occurred".chars()));
+        assert!(!ac.is_match("all good".chars()));
+    }
+
+    #[test]
+    fn tokens() {
+        let ac = AhoCorasick::new(vec![vec!["GET", "/admin"], vec!["POST"]]);
+        let log = vec!["GET", "/", "GET", "/admin", "POST", "/login"];
+        let counts = ac.count_matches(log);
+        assert_eq!(counts[&0], 1);
+        assert_eq!(counts[&1], 1);
+    }
+}
@@ -0,0 +1,315 @@
+use std::fmt::Write;
+
+/**
+Kind of a diff hunk.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DiffKind {
+    ///Elements present in both sequences
+    Equal,
+    ///Elements present only in the old sequence
+    Delete,
+    ///Elements present only in the new sequence
+    Insert,
+}
+
+/**
+Maximal group of consecutive elements of the same kind, created by ```myers_diff()```.
+
+Ranges ```(from, to)``` refer to the old and the new sequence. The range of the sequence
+that does not contain elements of the hunk is empty and points at the place of the change.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DiffHunk {
+    pub kind: DiffKind,
+    pub old: (usize, usize),
+    pub new: (usize, usize),
+}
+
+/**
+Finds the shortest edit script between two sequences using the Myers' algorithm.
+
+Returns hunks of equal, deleted and inserted elements that together cover both sequences in order.
+Deletions are placed before insertions. This is the algorithm used by ```diff``` and ```git```.
+
+**More:** <http://www.xmailserver.org/diff2.pdf>
+
+# Complexity
+
+- Processing complexity: O((n + m)*d)
+- Memory complexity: O(n + m + d^2)
+
+where d - number of deleted and inserted elements.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::{myers_diff, DiffHunk, DiffKind};
+
+fn main(){
+    let diff = myers_diff(b"ABCABBA", b"CBABAC");
+    //5 elements deleted or inserted
+    let changed: usize = diff.iter()
+        .filter(|h| h.kind != DiffKind::Equal)
+        .map(|h| h.old.1 - h.old.0 + h.new.1 - h.new.0)
+        .sum();
+    assert_eq!(changed, 5);
+    assert_eq!(diff[0], DiffHunk{kind: DiffKind::Delete, old: (0, 2), new: (0, 0)});
+}
+```
+*/
+pub fn myers_diff<T>(a: &[T], b: &[T]) -> Vec<DiffHunk>
+    where
+        T: Eq,
+{
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    //v[k + offset] - the furthest x reached on the diagonal k = x - y
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    //trace[d] - diagonals -d-1..=d+1 of v before the step d
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    'outer: for d in 0..max + 1 {
+        trace.push(v[(offset - d - 1) as usize..(offset + d + 2) as usize].to_vec());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                //move down: insertion
+                v[idx + 1]
+            } else {
+                //move right: deletion
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+            k += 2;
+        }
+    }
+    //walk back from the end, single elements in the reverse order
+    let mut steps: Vec<(DiffKind, usize, usize)> = Vec::with_capacity((n + m) as usize);
+    let (mut x, mut y) = (n, m);
+    for d in (0..trace.len() as isize).rev() {
+        let v = &trace[d as usize];
+        let at = |k: isize| v[(k + d + 1) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) { k + 1 } else { k - 1 };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            steps.push((DiffKind::Equal, x as usize, y as usize));
+        }
+        if d > 0 {
+            if x == prev_x {
+                steps.push((DiffKind::Insert, x as usize, prev_y as usize));
+            } else {
+                steps.push((DiffKind::Delete, prev_x as usize, y as usize));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    //merge single elements into hunks, moving deletions before insertions
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    for (kind, x, y) in steps.into_iter().rev() {
+        let (old_len, new_len) = match kind {
+            DiffKind::Equal => (1, 1),
+            DiffKind::Delete => (1, 0),
+            DiffKind::Insert => (0, 1),
+        };
+        if let Some(last) = hunks.last_mut() {
+            if last.kind == kind && last.old.1 == x && last.new.1 == y {
+                last.old.1 += old_len;
+                last.new.1 += new_len;
+                continue;
+            }
+        }
+        if kind == DiffKind::Delete {
+            let len = hunks.len();
+            if len >= 1 && hunks[len - 1].kind == DiffKind::Insert {
+                //insertion followed by deletion: swap them
+                let insert = hunks.pop().unwrap();
+                let extended = len >= 2 && hunks[len - 2].kind == DiffKind::Delete;
+                if extended {
+                    hunks[len - 2].old.1 += 1;
+                } else {
+                    hunks.push(DiffHunk { kind, old: (x, x + 1), new: (insert.new.0, insert.new.0) });
+                }
+                hunks.push(DiffHunk { kind: DiffKind::Insert, old: (x + 1, x + 1), new: insert.new });
+                continue;
+            }
+        }
+        hunks.push(DiffHunk { kind, old: (x, x + old_len), new: (y, y + new_len) });
+    }
+    hunks
+}
+
+//"start,count" part of the hunk header, the start of an empty range is the line before it
+fn header_range(from: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", from),
+        1 => format!("{}", from + 1),
+        _ => format!("{},{}", from + 1, count),
+    }
+}
+
+/**
+Renders the difference between two sequences of lines in the unified diff format.
+
+Every changed region is shown with up to ```context``` unchanged lines around it, regions
+closer than ```2*context``` lines are joined into one hunk. Only hunks are rendered, file
+headers (```---``` and ```+++``` lines) can be prepended by the caller. Lines should not contain
+line terminators. Returns an empty string if sequences are equal.
+
+**More:** <https://en.wikipedia.org/wiki/Diff#Unified_format>
+
+# Complexity
+
+- Processing complexity: O((n + m)*d)
+- Memory complexity: O(n + m + d^2)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::unified_diff;
+
+fn main(){
+    let old: Vec<&str> = "a\nb\nc\nd\ne".lines().collect();
+    let new: Vec<&str> = "a\nb\nx\nd\ne".lines().collect();
+    assert_eq!(unified_diff(&old, &new, 1), "@@ -2,3 +2,3 @@\n b\n-c\n+x\n d\n");
+}
+```
+*/
+pub fn unified_diff<S>(old: &[S], new: &[S], context: usize) -> String
+    where
+        S: AsRef<str> + Eq,
+{
+    //single lines: kind, position in the old sequence, position in the new sequence
+    let mut lines: Vec<(DiffKind, usize, usize)> = Vec::new();
+    for hunk in myers_diff(old, new) {
+        let len = (hunk.old.1 - hunk.old.0).max(hunk.new.1 - hunk.new.0);
+        for i in 0..len {
+            let (x, y) = match hunk.kind {
+                DiffKind::Equal => (hunk.old.0 + i, hunk.new.0 + i),
+                DiffKind::Delete => (hunk.old.0 + i, hunk.new.0),
+                DiffKind::Insert => (hunk.old.0, hunk.new.0 + i),
+            };
+            lines.push((hunk.kind, x, y));
+        }
+    }
+    let changes: Vec<usize> = (0..lines.len()).filter(|&i| lines[i].0 != DiffKind::Equal).collect();
+    let mut result = String::new();
+    let mut c = 0;
+    while c < changes.len() {
+        //join changes separated by at most 2*context equal lines
+        let mut last = c;
+        while last + 1 < changes.len() && changes[last + 1] - changes[last] - 1 <= 2 * context {
+            last += 1;
+        }
+        let from = changes[c].saturating_sub(context);
+        let to = lines.len().min(changes[last] + 1 + context);
+        let group = &lines[from..to];
+        let old_count = group.iter().filter(|l| l.0 != DiffKind::Insert).count();
+        let new_count = group.iter().filter(|l| l.0 != DiffKind::Delete).count();
+        writeln!(result, "@@ -{} +{} @@", header_range(group[0].1, old_count), header_range(group[0].2, new_count)).unwrap();
+        for &(kind, x, y) in group {
+            match kind {
+                DiffKind::Equal => writeln!(result, " {}", old[x].as_ref()),
+                DiffKind::Delete => writeln!(result, "-{}", old[x].as_ref()),
+                DiffKind::Insert => writeln!(result, "+{}", new[y].as_ref()),
+            }.unwrap();
+        }
+        c = last + 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strings::longest_common_subsequence;
+
+    //checks that hunks cover both sequences and returns the number of changed elements
+    fn check(a: &[u8], b: &[u8], hunks: &[DiffHunk]) -> usize {
+        let (mut x, mut y) = (0, 0);
+        let mut changed = 0;
+        for (idx, h) in hunks.iter().enumerate() {
+            assert_eq!((h.old.0, h.new.0), (x, y));
+            match h.kind {
+                DiffKind::Equal => {
+                    assert_eq!(h.old.1 - h.old.0, h.new.1 - h.new.0);
+                    assert_eq!(a[h.old.0..h.old.1], b[h.new.0..h.new.1]);
+                }
+                DiffKind::Delete => {
+                    assert_eq!(h.new.0, h.new.1);
+                    changed += h.old.1 - h.old.0;
+                }
+                DiffKind::Insert => {
+                    assert_eq!(h.old.0, h.old.1);
+                    changed += h.new.1 - h.new.0;
+                }
+            }
+            assert!(h.old.1 > h.old.0 || h.new.1 > h.new.0);
+            if idx > 0 {
+                let prev = hunks[idx - 1].kind;
+                assert!(prev != h.kind);
+                assert!(!(prev == DiffKind::Insert && h.kind == DiffKind::Delete));
+            }
+            x = h.old.1;
+            y = h.new.1;
+        }
+        assert_eq!((x, y), (a.len(), b.len()));
+        changed
+    }
+
+    //minimal number of insertions and deletions
+    fn indel_distance(a: &[u8], b: &[u8]) -> usize {
+        a.len() + b.len() - 2 * longest_common_subsequence(a, b).len()
+    }
+
+    #[test]
+    fn shortest_script() {
+        let mut words = Vec::new();
+        for n in 0..6 {
+            for mask in 0..1usize << n {
+                words.push((0..n).map(|i| (mask >> i & 1) as u8).collect::<Vec<u8>>());
+            }
+        }
+        for a in &words {
+            for b in &words {
+                let hunks = myers_diff(a, b);
+                assert_eq!(check(a, b, &hunks), indel_distance(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn equal_and_empty() {
+        assert!(myers_diff::<u8>(&[], &[]).is_empty());
+        assert_eq!(myers_diff(b"abc", b"abc"), vec![DiffHunk { kind: DiffKind::Equal, old: (0, 3), new: (0, 3) }]);
+        assert_eq!(myers_diff(b"", b"ab"), vec![DiffHunk { kind: DiffKind::Insert, old: (0, 0), new: (0, 2) }]);
+        assert_eq!(unified_diff(&["a", "b"], &["a", "b"], 3), "");
+    }
+
+    #[test]
+    fn unified() {
+        let old: Vec<String> = (1..21).map(|i| i.to_string()).collect();
+        let mut new = old.clone();
+        new.remove(1);
+        new[15] = "x".to_string();
+        new.push("21".to_string());
+        let expected = "@@ -1,4 +1,3 @@\n 1\n-2\n 3\n 4\n\
+                        @@ -15,6 +14,7 @@\n 15\n 16\n-17\n+x\n 18\n 19\n 20\n+21\n";
+        assert_eq!(unified_diff(&old, &new, 2), expected);
+        assert_eq!(unified_diff(&["a"], &[], 3), "@@ -1 +0,0 @@\n-a\n");
+    }
+}
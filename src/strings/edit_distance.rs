@@ -0,0 +1,549 @@
+use std::ops::Add;
+
+/**
+A single step of an edit script transforming one sequence into another.
+
+Indexes refer to the source sequence ```a``` and the target sequence ```b```.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EditOp {
+    ///a[i] is kept as b[j]
+    Keep(usize, usize),
+    ///a[i] is replaced with b[j]
+    Substitute(usize, usize),
+    ///a[i] is removed
+    Delete(usize),
+    ///b[j] is inserted
+    Insert(usize),
+}
+
+#[inline(always)]
+fn min_of<C: PartialOrd>(a: C, b: C) -> C {
+    if b < a { b } else { a }
+}
+
+/**
+Calculates the Levenshtein distance: the minimal number of insertions, deletions and substitutions
+needed to transform one sequence into another.
+
+**More:** <https://en.wikipedia.org/wiki/Levenshtein_distance>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::levenshtein;
+
+fn main(){
+    assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+}
+```
+*/
+pub fn levenshtein<T>(a: &[T], b: &[T]) -> usize
+    where
+        T: Eq,
+{
+    weighted_edit_distance(a, b, |_| 1, |_| 1, |_, _| 1)
+}
+
+/**
+Checks if the Levenshtein distance does not exceed k and returns it.
+
+Only cells of the dynamic programming table that are at most k steps away from the diagonal
+are calculated and the calculation stops as soon as a whole row exceeds k, so this is much
+faster than ```levenshtein()``` for small bounds.
+
+**More:** <https://en.wikipedia.org/wiki/Levenshtein_distance>
+
+# Complexity
+
+- Processing complexity: O(min(n, m)*k + m)
+- Memory complexity: O(m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::levenshtein_within;
+
+fn main(){
+    assert_eq!(levenshtein_within(b"kitten", b"sitting", 3), Some(3));
+    assert_eq!(levenshtein_within(b"kitten", b"sitting", 2), None);
+}
+```
+*/
+pub fn levenshtein_within<T>(a: &[T], b: &[T], k: usize) -> Option<usize>
+    where
+        T: Eq,
+{
+    let (n, m) = (a.len(), b.len());
+    if n.max(m) - n.min(m) > k {
+        return None;
+    }
+    //all values above k are equivalent
+    let cap = k.saturating_add(1);
+    let mut prev = vec![cap; m + 1];
+    let mut cur = vec![cap; m + 1];
+    for (j, val) in prev.iter_mut().enumerate().take(m.min(k) + 1) {
+        *val = j;
+    }
+    for i in 1..n + 1 {
+        //the band of columns [lo, hi]
+        let lo = i.saturating_sub(k).max(1);
+        let hi = m.min(i.saturating_add(k));
+        cur[lo - 1] = if lo == 1 && i <= k { i } else { cap };
+        let mut row_min = cur[lo - 1];
+        for j in lo..hi + 1 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let val = (prev[j - 1] + cost).min(prev[j] + 1).min(cur[j - 1] + 1).min(cap);
+            cur[j] = val;
+            row_min = row_min.min(val);
+        }
+        //the next row reads one column more from this one
+        if hi < m {
+            cur[hi + 1] = cap;
+        }
+        if row_min > k {
+            return None;
+        }
+        ::std::mem::swap(&mut prev, &mut cur);
+    }
+    if prev[m] <= k { Some(prev[m]) } else { None }
+}
+
+/**
+Calculates the Hamming distance: the number of positions at which elements differ.
+
+Panics if sequences have different lengths.
+
+**More:** <https://en.wikipedia.org/wiki/Hamming_distance>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(1)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::hamming;
+
+fn main(){
+    assert_eq!(hamming(b"karolin", b"kathrin"), 3);
+}
+```
+*/
+pub fn hamming<T>(a: &[T], b: &[T]) -> usize
+    where
+        T: Eq,
+{
+    assert_eq!(a.len(), b.len(), "Sequences must have equal lengths");
+    a.iter().zip(b).filter(|&(x, y)| x != y).count()
+}
+
+/**
+Calculates the optimal string alignment distance: the Levenshtein distance extended with
+transpositions of two adjacent elements, where no substring is edited more than once.
+
+Unlike ```damerau_levenshtein()``` this is not a metric: "ca" -> "abc" needs 3 edits,
+because "ac" obtained by the transposition cannot be edited further.
+
+**More:** <https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Optimal_string_alignment_distance>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::osa_distance;
+
+fn main(){
+    assert_eq!(osa_distance(b"abcd", b"acbd"), 1);
+    assert_eq!(osa_distance(b"ca", b"abc"), 3);
+}
+```
+*/
+pub fn osa_distance<T>(a: &[T], b: &[T]) -> usize
+    where
+        T: Eq,
+{
+    let m = b.len();
+    let mut before = vec![0usize; m + 1];
+    let mut prev: Vec<usize> = (0..m + 1).collect();
+    let mut cur = vec![0usize; m + 1];
+    for i in 1..a.len() + 1 {
+        cur[0] = i;
+        for j in 1..m + 1 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut val = (prev[j - 1] + cost).min(prev[j] + 1).min(cur[j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                val = val.min(before[j - 2] + 1);
+            }
+            cur[j] = val;
+        }
+        //rotate rows: before <- prev <- cur
+        ::std::mem::swap(&mut before, &mut prev);
+        ::std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[m]
+}
+
+/**
+Calculates the Damerau–Levenshtein distance: the minimal number of insertions, deletions,
+substitutions and transpositions of two adjacent elements needed to transform one sequence into another.
+
+Unlike ```osa_distance()``` substrings may be edited after being transposed, so this is a metric.
+
+**More:** <https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(n*m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::damerau_levenshtein;
+
+fn main(){
+    assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+}
+```
+*/
+pub fn damerau_levenshtein<T>(a: &[T], b: &[T]) -> usize
+    where
+        T: Eq,
+{
+    let (n, m) = (a.len(), b.len());
+    let inf = n + m;
+    //d[i + 1][j + 1] is the distance between prefixes of lengths i and j, row and column 0 are sentinels
+    let mut d = vec![vec![inf; m + 2]; n + 2];
+    for i in 0..n + 1 {
+        d[i + 1][1] = i;
+    }
+    for j in 0..m + 1 {
+        d[1][j + 1] = j;
+    }
+    //last_row[j] - the last processed row i (1-based) with a[i-1] == b[j-1], 0 if none
+    let mut last_row = vec![0usize; m + 1];
+    for i in 1..n + 1 {
+        //the last column j (1-based) in this row with a[i-1] == b[j-1], 0 if none
+        let mut last_col = 0;
+        for j in 1..m + 1 {
+            let (k, l) = (last_row[j], last_col);
+            let cost = if a[i - 1] == b[j - 1] {
+                last_col = j;
+                0
+            } else {
+                1
+            };
+            //transposition of a[k-1] and b[l-1] with everything between them deleted or inserted
+            let transposition = d[k][l] + (i - k - 1) + 1 + (j - l - 1);
+            d[i + 1][j + 1] = (d[i][j] + cost)
+                .min(d[i + 1][j] + 1)
+                .min(d[i][j + 1] + 1)
+                .min(transposition);
+        }
+        for j in 1..m + 1 {
+            if a[i - 1] == b[j - 1] {
+                last_row[j] = i;
+            }
+        }
+    }
+    d[n + 1][m + 1]
+}
+
+/**
+Calculates the edit distance with custom costs of inserting, deleting and substituting elements.
+
+Keeping an element that is equal in both sequences is free, the substitution cost
+is calculated only for different elements. Costs should not be negative.
+
+**More:** <https://en.wikipedia.org/wiki/Edit_distance>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::weighted_edit_distance;
+
+fn main(){
+    //substitution is more expensive than deletion and insertion together
+    let dist = weighted_edit_distance(b"abc", b"axc", |_| 1, |_| 1, |_, _| 3);
+    assert_eq!(dist, 2);
+    let dist = weighted_edit_distance(b"abc", b"ab", |_| 0.5, |_| 0.25, |_, _| 1.0);
+    assert_eq!(dist, 0.25);
+}
+```
+*/
+pub fn weighted_edit_distance<T, C, I, D, S>(a: &[T], b: &[T], mut insert_cost: I, mut delete_cost: D, mut substitute_cost: S) -> C
+    where
+        T: Eq,
+        C: Copy + PartialOrd + Add<Output = C> + Default,
+        I: FnMut(&T) -> C,
+        D: FnMut(&T) -> C,
+        S: FnMut(&T, &T) -> C,
+{
+    let m = b.len();
+    let mut prev = Vec::with_capacity(m + 1);
+    prev.push(C::default());
+    for (j, elem) in b.iter().enumerate() {
+        let val = prev[j] + insert_cost(elem);
+        prev.push(val);
+    }
+    let mut cur = prev.clone();
+    for x in a {
+        cur[0] = prev[0] + delete_cost(x);
+        for (j, y) in b.iter().enumerate() {
+            let diagonal = if x == y { prev[j] } else { prev[j] + substitute_cost(x, y) };
+            cur[j + 1] = min_of(min_of(diagonal, prev[j + 1] + delete_cost(x)), cur[j] + insert_cost(y));
+        }
+        ::std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[m]
+}
+
+/**
+Calculates the Levenshtein distance together with an optimal edit script.
+
+The script lists operations in the order of both sequences, see ```EditOp```.
+
+**More:** <https://en.wikipedia.org/wiki/Levenshtein_distance>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(n*m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::{levenshtein_alignment, EditOp};
+
+fn main(){
+    let (dist, script) = levenshtein_alignment(b"abc", b"bd");
+    assert_eq!(dist, 2);
+    assert_eq!(script, vec![EditOp::Delete(0), EditOp::Keep(1, 0), EditOp::Substitute(2, 1)]);
+}
+```
+*/
+pub fn levenshtein_alignment<T>(a: &[T], b: &[T]) -> (usize, Vec<EditOp>)
+    where
+        T: Eq,
+{
+    weighted_alignment(a, b, |_| 1, |_| 1, |_, _| 1)
+}
+
+/**
+Calculates the weighted edit distance together with an optimal edit script.
+
+Costs work in the same way as in ```weighted_edit_distance()```. If there are several
+optimal scripts, keeping and substituting elements is preferred over deleting them,
+and deleting is preferred over inserting.
+
+**More:** <https://en.wikipedia.org/wiki/Edit_distance>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(n*m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::{weighted_alignment, EditOp};
+
+fn main(){
+    //substitution is more expensive than deletion and insertion together
+    let (cost, script) = weighted_alignment(b"abc", b"xbc", |_| 1, |_| 1, |_, _| 5);
+    assert_eq!(cost, 2);
+    assert_eq!(script, vec![EditOp::Insert(0), EditOp::Delete(0), EditOp::Keep(1, 1), EditOp::Keep(2, 2)]);
+}
+```
+*/
+pub fn weighted_alignment<T, C, I, D, S>(a: &[T], b: &[T], mut insert_cost: I, mut delete_cost: D, mut substitute_cost: S) -> (C, Vec<EditOp>)
+    where
+        T: Eq,
+        C: Copy + PartialOrd + Add<Output = C> + Default,
+        I: FnMut(&T) -> C,
+        D: FnMut(&T) -> C,
+        S: FnMut(&T, &T) -> C,
+{
+    let (n, m) = (a.len(), b.len());
+    let ins: Vec<C> = b.iter().map(&mut insert_cost).collect();
+    let del: Vec<C> = a.iter().map(&mut delete_cost).collect();
+    let mut d = vec![vec![C::default(); m + 1]; n + 1];
+    for j in 0..m {
+        d[0][j + 1] = d[0][j] + ins[j];
+    }
+    for i in 0..n {
+        d[i + 1][0] = d[i][0] + del[i];
+        for j in 0..m {
+            let diagonal = if a[i] == b[j] { d[i][j] } else { d[i][j] + substitute_cost(&a[i], &b[j]) };
+            d[i + 1][j + 1] = min_of(min_of(diagonal, d[i][j + 1] + del[i]), d[i + 1][j] + ins[j]);
+        }
+    }
+    //walk back from the end, recomputing candidates gives exactly the same values
+    let mut script = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        let target = d[i][j];
+        if i > 0 && j > 0 {
+            if a[i - 1] == b[j - 1] {
+                if d[i - 1][j - 1] == target {
+                    script.push(EditOp::Keep(i - 1, j - 1));
+                    i -= 1;
+                    j -= 1;
+                    continue;
+                }
+            } else if d[i - 1][j - 1] + substitute_cost(&a[i - 1], &b[j - 1]) == target {
+                script.push(EditOp::Substitute(i - 1, j - 1));
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && d[i - 1][j] + del[i - 1] == target {
+            script.push(EditOp::Delete(i - 1));
+            i -= 1;
+        } else {
+            script.push(EditOp::Insert(j - 1));
+            j -= 1;
+        }
+    }
+    script.reverse();
+    (d[n][m], script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_binary(max_len: usize) -> Vec<Vec<u8>> {
+        let mut result = Vec::new();
+        for n in 0..max_len + 1 {
+            for mask in 0..1usize << n {
+                result.push((0..n).map(|i| (mask >> i & 1) as u8).collect());
+            }
+        }
+        result
+    }
+
+    fn apply(a: &[u8], b: &[u8], script: &[EditOp]) -> Vec<u8> {
+        let (mut i, mut j) = (0, 0);
+        let mut result = Vec::new();
+        for &op in script {
+            match op {
+                EditOp::Keep(x, y) => {
+                    assert_eq!((x, y), (i, j));
+                    assert_eq!(a[x], b[y]);
+                    result.push(a[x]);
+                    i += 1;
+                    j += 1;
+                }
+                EditOp::Substitute(x, y) => {
+                    assert_eq!((x, y), (i, j));
+                    assert_ne!(a[x], b[y]);
+                    result.push(b[y]);
+                    i += 1;
+                    j += 1;
+                }
+                EditOp::Delete(x) => {
+                    assert_eq!(x, i);
+                    i += 1;
+                }
+                EditOp::Insert(y) => {
+                    assert_eq!(y, j);
+                    result.push(b[y]);
+                    j += 1;
+                }
+            }
+        }
+        assert_eq!((i, j), (a.len(), b.len()));
+        result
+    }
+
+    fn edits(script: &[EditOp]) -> usize {
+        script.iter().filter(|op| !matches!(**op, EditOp::Keep(_, _))).count()
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(levenshtein(b"", b"abc"), 3);
+        assert_eq!(levenshtein(b"flaw", b"lawn"), 2);
+        assert_eq!(levenshtein(b"saturday", b"sunday"), 3);
+        assert_eq!(osa_distance(b"", b""), 0);
+        assert_eq!(osa_distance(b"ab", b"ba"), 1);
+        assert_eq!(damerau_levenshtein(b"ab", b"ba"), 1);
+        assert_eq!(damerau_levenshtein(b"", b"ab"), 2);
+        assert_eq!(damerau_levenshtein(b"abcdef", b"badcfe"), 3);
+        assert_eq!(hamming(&[1, 2, 3], &[1, 3, 2]), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn hamming_different_lengths() {
+        hamming(b"ab", b"abc");
+    }
+
+    #[test]
+    fn consistency() {
+        let words = all_binary(5);
+        for a in &words {
+            for b in &words {
+                let lev = levenshtein(a, b);
+                let osa = osa_distance(a, b);
+                let dl = damerau_levenshtein(a, b);
+                assert!(dl <= osa && osa <= lev);
+                assert_eq!(dl, damerau_levenshtein(b, a));
+                for k in 0..6 {
+                    assert_eq!(levenshtein_within(a, b, k), if lev <= k { Some(lev) } else { None });
+                }
+                let (dist, script) = levenshtein_alignment(a, b);
+                assert_eq!(dist, lev);
+                assert_eq!(edits(&script), lev);
+                assert_eq!(apply(a, b, &script), *b);
+            }
+        }
+    }
+
+    #[test]
+    fn damerau_triangle_inequality() {
+        let words = all_binary(4);
+        for a in &words {
+            for b in &words {
+                for c in &words {
+                    let ab = damerau_levenshtein(a, b);
+                    assert!(ab <= damerau_levenshtein(a, c) + damerau_levenshtein(c, b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let a = b"sunday";
+        let b = b"saturday";
+        //only insertions and deletions
+        let dist = weighted_edit_distance(a, b, |_| 1, |_| 1, |_, _| 2);
+        assert_eq!(dist, 4);
+        let (cost, script) = weighted_alignment(a, b, |_| 1, |_| 1, |_, _| 3);
+        assert_eq!(cost, 4);
+        assert_eq!(apply(a, b, &script), b.to_vec());
+        assert!(!script.iter().any(|op| matches!(*op, EditOp::Substitute(_, _))));
+        //cost depends on elements
+        let dist = weighted_edit_distance(b"ab", b"ac", |_| 10u32, |_| 10, |&x, &y| (x as u32 + y as u32) % 7);
+        assert_eq!(dist, (b'b' as u32 + b'c' as u32) % 7);
+    }
+}
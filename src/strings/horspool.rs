@@ -0,0 +1,119 @@
+use fnv::FnvHashMap;
+use std::hash::Hash;
+
+/**
+Iterator over positions of a pattern in a text, created by ```horspool_find_all()```.
+*/
+#[derive(Clone, Debug)]
+pub struct HorspoolMatches<'a, T: 'a> {
+    text: &'a [T],
+    pattern: &'a [T],
+    //distance from the last occurrence of an element (excluding the last one) to the end of the pattern
+    shifts: FnvHashMap<&'a T, usize>,
+    pos: usize,
+}
+
+impl<'a, T> Iterator for HorspoolMatches<'a, T>
+    where
+        T: Eq + Hash,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let (n, m) = (self.text.len(), self.pattern.len());
+        if m == 0 {
+            //empty pattern matches at every position, including the end of the text
+            if self.pos > n {
+                return None;
+            }
+            self.pos += 1;
+            return Some(self.pos - 1);
+        }
+        while self.pos + m <= n {
+            let pos = self.pos;
+            let window = &self.text[pos..pos + m];
+            //the last element is compared first, it also decides about the shift
+            let last = &window[m - 1];
+            self.pos += *self.shifts.get(last).unwrap_or(&m);
+            if window.iter().rev().eq(self.pattern.iter().rev()) {
+                return Some(pos);
+            }
+        }
+        None
+    }
+}
+
+/**
+Finds all (possibly overlapping) occurrences of the pattern in the text using the Boyer–Moore–Horspool algorithm.
+
+Returns an iterator of positions where matches start. The pattern is compared from its end
+and after a mismatch the window is shifted according to the last element of the window, so
+in practice most of the text is skipped. This is especially efficient for long patterns
+over big alphabets. An empty pattern matches at every position.
+
+**More:** <https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore%E2%80%93Horspool_algorithm>
+
+# Complexity
+
+- Average processing complexity: O(n/m + m)
+- Worst case processing complexity: O(n*m)
+- Memory complexity: O(m)
+
+where n - length of the text, m - length of the pattern.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::horspool_find_all;
+
+fn main(){
+    let text = b"here is a simple example, an example";
+    assert_eq!(horspool_find_all(text, b"example").collect::<Vec<_>>(), vec![17, 29]);
+}
+```
+*/
+pub fn horspool_find_all<'a, T>(text: &'a [T], pattern: &'a [T]) -> HorspoolMatches<'a, T>
+    where
+        T: Eq + Hash,
+{
+    let m = pattern.len();
+    let mut shifts = FnvHashMap::default();
+    if m > 0 {
+        for (i, elem) in pattern[..m - 1].iter().enumerate() {
+            shifts.insert(elem, m - 1 - i);
+        }
+    }
+    HorspoolMatches {
+        text,
+        pattern,
+        shifts,
+        pos: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_matches_naive() {
+        let text = b"aabaabaaabaabaabaab";
+        for pattern in &[&b"a"[..], b"aab", b"aabaab", b"baaab", b"abc", b"aabaabaaabaabaabaab", b"aabaabaaabaabaabaabx"] {
+            let expected: Vec<usize> = (0..text.len())
+                .filter(|&i| text[i..].starts_with(pattern))
+                .collect();
+            assert_eq!(horspool_find_all(text, pattern).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn tokens() {
+        let text = ["GET", "/", "POST", "/", "GET", "/", "GET"];
+        assert_eq!(horspool_find_all(&text, &["/", "GET"]).collect::<Vec<_>>(), vec![3, 5]);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(horspool_find_all(b"ab", b"").collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,164 @@
+/**
+Calculates the prefix function of the sequence.
+
+The value at index i is the length of the longest proper prefix of ```s[..i+1]```
+that is also its suffix. This is the failure function used by the Knuth–Morris–Pratt algorithm.
+
+**More:** <https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::prefix_function;
+
+fn main(){
+    assert_eq!(prefix_function(b"abacaba"), vec![0, 0, 1, 0, 1, 2, 3]);
+    assert_eq!(prefix_function(&[1, 1, 1]), vec![0, 1, 2]);
+}
+```
+*/
+pub fn prefix_function<T>(s: &[T]) -> Vec<usize>
+    where
+        T: Eq,
+{
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/**
+Iterator over positions of a pattern in a text, created by ```kmp_find_all()```.
+*/
+#[derive(Clone, Debug)]
+pub struct KmpMatches<'a, T: 'a> {
+    text: &'a [T],
+    pattern: &'a [T],
+    prefix: Vec<usize>,
+    pos: usize,
+    matched: usize,
+}
+
+impl<'a, T> Iterator for KmpMatches<'a, T>
+    where
+        T: Eq,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let m = self.pattern.len();
+        if m == 0 {
+            //empty pattern matches at every position, including the end of the text
+            if self.pos > self.text.len() {
+                return None;
+            }
+            self.pos += 1;
+            return Some(self.pos - 1);
+        }
+        while self.pos < self.text.len() {
+            let curr = &self.text[self.pos];
+            self.pos += 1;
+            while self.matched > 0 && self.pattern[self.matched] != *curr {
+                self.matched = self.prefix[self.matched - 1];
+            }
+            if self.pattern[self.matched] == *curr {
+                self.matched += 1;
+            }
+            if self.matched == m {
+                self.matched = self.prefix[m - 1];
+                return Some(self.pos - m);
+            }
+        }
+        None
+    }
+}
+
+/**
+Finds all (possibly overlapping) occurrences of the pattern in the text using the Knuth–Morris–Pratt algorithm.
+
+Returns an iterator of positions where matches start. Works with any sequences of comparable
+elements: bytes, chars, tokens. An empty pattern matches at every position.
+
+**More:** <https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm>
+
+# Complexity
+
+- Processing complexity: O(n + m)
+- Memory complexity: O(m)
+
+where n - length of the text, m - length of the pattern.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::kmp_find_all;
+
+fn main(){
+    let found: Vec<usize> = kmp_find_all(b"abababa", b"aba").collect();
+    assert_eq!(found, vec![0, 2, 4]);
+    let words = ["to", "be", "or", "not", "to", "be"];
+    assert_eq!(kmp_find_all(&words, &["to", "be"]).collect::<Vec<_>>(), vec![0, 4]);
+}
+```
+*/
+pub fn kmp_find_all<'a, T>(text: &'a [T], pattern: &'a [T]) -> KmpMatches<'a, T>
+    where
+        T: Eq,
+{
+    KmpMatches {
+        text,
+        pattern,
+        prefix: prefix_function(pattern),
+        pos: 0,
+        matched: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..text.len() + 1)
+            .filter(|&i| i + pattern.len() <= text.len() && &text[i..i + pattern.len()] == pattern)
+            .collect()
+    }
+
+    #[test]
+    fn prefix_empty() {
+        let s: [u8; 0] = [];
+        assert!(prefix_function(&s).is_empty());
+    }
+
+    #[test]
+    fn find_matches_naive() {
+        let text = b"aabaabaaabaabaabaab";
+        for pattern in &[&b"a"[..], b"aab", b"aabaab", b"baaab", b"abc", b"aabaabaaabaabaabaab", b"aabaabaaabaabaabaabx"] {
+            assert_eq!(kmp_find_all(text, pattern).collect::<Vec<_>>(), naive(text, pattern));
+        }
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(kmp_find_all(b"abc", b"").collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(kmp_find_all(b"", b"").collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn empty_text() {
+        assert_eq!(kmp_find_all(b"", b"a").next(), None);
+    }
+}
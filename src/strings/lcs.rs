@@ -0,0 +1,173 @@
+use fnv::FnvHashMap;
+use search::binary_first_by;
+use std::hash::Hash;
+
+/**
+Finds the longest common subsequence of two sequences.
+
+Returns pairs of indexes ```(i, j)``` such that ```a[i] == b[j]```, both increasing.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_common_subsequence_problem>
+
+# Complexity
+
+- Processing complexity: O(n*m)
+- Memory complexity: O(n*m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::longest_common_subsequence;
+
+fn main(){
+    let (a, b) = (b"ABCBDAB", b"BDCABA");
+    let lcs = longest_common_subsequence(a, b);
+    let common: Vec<u8> = lcs.iter().map(|&(i, _)| a[i]).collect();
+    assert_eq!(common, b"BDAB".to_vec());
+}
+```
+*/
+pub fn longest_common_subsequence<T>(a: &[T], b: &[T]) -> Vec<(usize, usize)>
+    where
+        T: Eq,
+{
+    let (n, m) = (a.len(), b.len());
+    //lengths[i][j] - length of the longest common subsequence of a[i..] and b[j..]
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut result = Vec::with_capacity(lengths[0][0]);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            result.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+//one matching pair on a candidate chain
+struct Node {
+    i: usize,
+    j: usize,
+    prev: Option<usize>,
+}
+
+/**
+Finds the longest common subsequence of two sequences using the Hunt–Szymanski algorithm.
+
+The problem is reduced to finding the longest increasing subsequence of positions of matching pairs,
+so it is fast when there are few matching pairs (e.g. lines of files). Returns pairs
+of indexes ```(i, j)``` such that ```a[i] == b[j]```, both increasing.
+
+**More:** <https://en.wikipedia.org/wiki/Hunt%E2%80%93Szymanski_algorithm>
+
+# Complexity
+
+- Processing complexity: O((r + n)*log(n) + m)
+- Memory complexity: O(r + m)
+
+where r - number of matching pairs.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::hunt_szymanski;
+
+fn main(){
+    let a = ["fn main() {", "let x = 1;", "println!(x);", "}"];
+    let b = ["fn main() {", "let x = 2;", "println!(x);", "}"];
+    assert_eq!(hunt_szymanski(&a, &b), vec![(0, 0), (2, 2), (3, 3)]);
+}
+```
+*/
+pub fn hunt_szymanski<T>(a: &[T], b: &[T]) -> Vec<(usize, usize)>
+    where
+        T: Eq + Hash,
+{
+    let mut positions: FnvHashMap<&T, Vec<usize>> = FnvHashMap::default();
+    for (j, elem) in b.iter().enumerate().rev() {
+        positions.entry(elem).or_default().push(j);
+    }
+    let mut nodes: Vec<Node> = Vec::new();
+    //tails[l] - node ending a common subsequence of length l+1 with the smallest j
+    let mut tails: Vec<usize> = Vec::new();
+    for (i, elem) in a.iter().enumerate() {
+        //positions are in decreasing order, so at most one pair with the same i is used in a chain
+        for &j in positions.get(elem).map_or(&[][..], |p| &p[..]) {
+            let pos = binary_first_by(&tails, |&t| nodes[t].j >= j).unwrap_or(tails.len());
+            let prev = if pos > 0 { Some(tails[pos - 1]) } else { None };
+            nodes.push(Node { i, j, prev });
+            if pos == tails.len() {
+                tails.push(nodes.len() - 1);
+            } else {
+                tails[pos] = nodes.len() - 1;
+            }
+        }
+    }
+    let mut result = Vec::with_capacity(tails.len());
+    let mut node = tails.last().cloned();
+    while let Some(idx) = node {
+        result.push((nodes[idx].i, nodes[idx].j));
+        node = nodes[idx].prev;
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_common_subsequence(a: &[u8], b: &[u8], pairs: &[(usize, usize)]) -> bool {
+        pairs.iter().all(|&(i, j)| a[i] == b[j])
+            && pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1)
+    }
+
+    //length of the longest common subsequence using recursion
+    fn naive_len(a: &[u8], b: &[u8]) -> usize {
+        if a.is_empty() || b.is_empty() {
+            0
+        } else if a[0] == b[0] {
+            1 + naive_len(&a[1..], &b[1..])
+        } else {
+            naive_len(&a[1..], b).max(naive_len(a, &b[1..]))
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(longest_common_subsequence::<u8>(&[], b"abc").is_empty());
+        assert!(hunt_szymanski(b"abc", b"").is_empty());
+        assert!(hunt_szymanski(b"abc", b"def").is_empty());
+    }
+
+    #[test]
+    fn matches_naive() {
+        let words: [&[u8]; 8] = [b"", b"a", b"abc", b"acbacb", b"bbbaaa", b"abcabcab", b"cabbac", b"aaaa"];
+        for a in &words {
+            for b in &words {
+                let expected = naive_len(a, b);
+                let dp = longest_common_subsequence(a, b);
+                assert_eq!(dp.len(), expected);
+                assert!(is_common_subsequence(a, b, &dp));
+                let hs = hunt_szymanski(a, b);
+                assert_eq!(hs.len(), expected);
+                assert!(is_common_subsequence(a, b, &hs));
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+/**
+Finds radii of the longest palindromes around every center using the Manacher's algorithm.
+
+A sequence of length n has 2n-1 centers: even indexes 2i are centers at the element i
+(palindromes of odd length), odd indexes 2i+1 are centers between elements i and i+1
+(palindromes of even length). The radius is the number of elements of the palindrome on each side
+of the center, excluding the center element, so the palindrome around the center c with the radius r
+occupies the range ```((c+1)/2-r, c/2+1+r)``` and has the length ```2r+1``` or ```2r```.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_palindromic_substring>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::manacher;
+
+fn main(){
+    //centers: a, a|b, b, b|b, b, b|a, a
+    assert_eq!(manacher(b"abba"), vec![0, 0, 0, 2, 0, 0, 0]);
+    assert_eq!(manacher(b"aba"), vec![0, 0, 1, 0, 0]);
+}
+```
+*/
+pub fn manacher<T>(s: &[T]) -> Vec<usize>
+    where
+        T: Eq,
+{
+    let n = s.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    //palindromes in the sequence with a virtual separator between every two elements
+    //all have odd lengths, radius[c] is the number of elements strictly inside its right half
+    let m = 2 * n - 1;
+    let elem_eq = |a: usize, b: usize| a % 2 == 1 || s[a / 2] == s[b / 2];
+    let mut radius = vec![0usize; m];
+    //the palindrome reaching the furthest to the right: (center, right end inclusive)
+    let (mut center, mut right) = (0, 0);
+    for c in 0..m {
+        let mut r = if c < right {
+            radius[2 * center - c].min(right - c)
+        } else {
+            0
+        };
+        while c > r && c + r + 1 < m && elem_eq(c - r - 1, c + r + 1) {
+            r += 1;
+        }
+        radius[c] = r;
+        if c + r > right {
+            center = c;
+            right = c + r;
+        }
+    }
+    //count real elements inside the right halves
+    radius.iter().enumerate().map(|(c, &r)| {
+        if c % 2 == 0 {
+            //separators and elements alternate, the center is an element
+            r / 2
+        } else {
+            //the center is a separator
+            r.div_ceil(2)
+        }
+    }).collect()
+}
+
+/**
+Finds the longest palindromic substring.
+
+Returns the range ```(from, to)``` of the first longest palindrome, ```(0, 0)``` for an empty sequence.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_palindromic_substring>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::longest_palindrome_idx;
+
+fn main(){
+    let text = b"forgeeksskeegfor";
+    let (from, to) = longest_palindrome_idx(text);
+    assert_eq!(&text[from..to], b"geeksskeeg");
+}
+```
+*/
+pub fn longest_palindrome_idx<T>(s: &[T]) -> (usize, usize)
+    where
+        T: Eq,
+{
+    let mut best = (0, 0);
+    for (c, &r) in manacher(s).iter().enumerate() {
+        let len = 2 * r + 1 - c % 2;
+        if len > best.1 - best.0 {
+            let from = c.div_ceil(2) - r;
+            best = (from, from + len);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    //radius is a half of the longest palindrome's length
+    fn naive(s: &[u8]) -> Vec<usize> {
+        let n = s.len();
+        (0..2 * n).take(2 * n - usize::from(n > 0)).map(|c| {
+            (0..n + 1).filter(|&len| {
+                len % 2 == (c + 1) % 2 && len <= c + 1 && (c + 1 + len) / 2 <= n
+                    && is_palindrome(&s[(c + 1 - len) / 2..(c + 1 + len) / 2])
+            }).max().unwrap() / 2
+        }).collect()
+    }
+
+    #[test]
+    fn matches_naive() {
+        for s in &[&b""[..], b"a", b"aa", b"ab", b"aaaa", b"abacaba", b"abaababaabaab", b"babcbabcbaccba", b"abcde"] {
+            assert_eq!(manacher(s), naive(s));
+        }
+    }
+
+    #[test]
+    fn all_binary() {
+        for n in 1..10 {
+            for mask in 0..1u32 << n {
+                let s: Vec<u8> = (0..n).map(|i| (mask >> i & 1) as u8).collect();
+                assert_eq!(manacher(&s), naive(&s));
+            }
+        }
+    }
+
+    #[test]
+    fn longest() {
+        assert_eq!(longest_palindrome_idx::<u8>(&[]), (0, 0));
+        assert_eq!(longest_palindrome_idx(b"abc"), (0, 1));
+        assert_eq!(longest_palindrome_idx(b"abcxabba"), (4, 8));
+        assert_eq!(longest_palindrome_idx(&[1, 2, 3, 2, 1, 1]), (0, 5));
+    }
+}
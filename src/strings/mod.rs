@@ -0,0 +1,30 @@
+/*!
+String algorithms.
+
+All algorithms work on slices of any comparable elements: bytes, chars or tokens.
+*/
+
+mod kmp;
+mod z_function;
+mod horspool;
+mod rabin_karp;
+mod aho_corasick;
+mod suffix_array;
+mod suffix_automaton;
+mod manacher;
+mod edit_distance;
+mod lcs;
+mod diff;
+
+pub use self::kmp::{prefix_function, kmp_find_all, KmpMatches};
+pub use self::z_function::{z_function, z_find_all, ZMatches};
+pub use self::horspool::{horspool_find_all, HorspoolMatches};
+pub use self::rabin_karp::{rabin_karp_find_all, RabinKarpMatches};
+pub use self::aho_corasick::{AhoCorasick, FindIter, Match, MatchKind};
+pub use self::suffix_array::{suffix_array, lcp_array, longest_common_substring, SuffixArray};
+pub use self::suffix_automaton::SuffixAutomaton;
+pub use self::manacher::{manacher, longest_palindrome_idx};
+pub use self::edit_distance::{levenshtein, levenshtein_within, hamming, osa_distance, damerau_levenshtein,
+    weighted_edit_distance, levenshtein_alignment, weighted_alignment, EditOp};
+pub use self::lcs::{longest_common_subsequence, hunt_szymanski};
+pub use self::diff::{myers_diff, unified_diff, DiffHunk, DiffKind};
@@ -0,0 +1,141 @@
+use fnv::FnvHasher;
+use std::hash::{Hash, Hasher};
+
+//the rolling hash is calculated modulo the Mersenne prime 2^61-1
+const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 1_000_003;
+
+#[inline(always)]
+fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % MODULUS as u128) as u64
+}
+
+#[inline(always)]
+fn hash_elem<T: Hash>(elem: &T) -> u64 {
+    let mut hasher = FnvHasher::default();
+    elem.hash(&mut hasher);
+    hasher.finish() % MODULUS
+}
+
+//polynomial hash of the sequence
+fn hash_seq<T: Hash>(seq: &[T]) -> u64 {
+    seq.iter().fold(0, |acc, elem| (mul_mod(acc, BASE) + hash_elem(elem)) % MODULUS)
+}
+
+/**
+Iterator over positions of a pattern in a text, created by ```rabin_karp_find_all()```.
+*/
+#[derive(Clone, Debug)]
+pub struct RabinKarpMatches<'a, T: 'a> {
+    text: &'a [T],
+    pattern: &'a [T],
+    pattern_hash: u64,
+    //hash of the current window of the text
+    window_hash: u64,
+    //BASE^(m-1), weight of the element leaving the window
+    high_power: u64,
+    pos: usize,
+}
+
+impl<'a, T> Iterator for RabinKarpMatches<'a, T>
+    where
+        T: Eq + Hash,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let (n, m) = (self.text.len(), self.pattern.len());
+        while self.pos + m <= n {
+            let pos = self.pos;
+            self.pos += 1;
+            let found = self.window_hash == self.pattern_hash && self.text[pos..pos + m] == *self.pattern;
+            if m > 0 && pos + m < n {
+                //roll the hash: remove the first element and append the next one
+                let first = mul_mod(hash_elem(&self.text[pos]), self.high_power);
+                let without_first = (self.window_hash + MODULUS - first) % MODULUS;
+                self.window_hash = (mul_mod(without_first, BASE) + hash_elem(&self.text[pos + m])) % MODULUS;
+            }
+            if found {
+                return Some(pos);
+            }
+        }
+        None
+    }
+}
+
+/**
+Finds all (possibly overlapping) occurrences of the pattern in the text using the Rabin–Karp algorithm.
+
+Returns an iterator of positions where matches start. A polynomial rolling hash of the current
+window of the text is compared with the hash of the pattern and only windows with equal hashes
+are compared element by element, so there are no false matches. An empty pattern matches
+at every position.
+
+**More:** <https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm>
+
+# Complexity
+
+- Average processing complexity: O(n + m)
+- Worst case processing complexity: O(n*m)
+- Memory complexity: O(1)
+
+where n - length of the text, m - length of the pattern.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::rabin_karp_find_all;
+
+fn main(){
+    let dna = b"GATTACAGATTACA";
+    assert_eq!(rabin_karp_find_all(dna, b"TACA").collect::<Vec<_>>(), vec![3, 10]);
+}
+```
+*/
+pub fn rabin_karp_find_all<'a, T>(text: &'a [T], pattern: &'a [T]) -> RabinKarpMatches<'a, T>
+    where
+        T: Eq + Hash,
+{
+    let m = pattern.len();
+    let high_power = (1..m).fold(1, |acc, _| mul_mod(acc, BASE));
+    let window_hash = if m <= text.len() { hash_seq(&text[..m]) } else { 0 };
+    RabinKarpMatches {
+        text,
+        pattern,
+        pattern_hash: hash_seq(pattern),
+        window_hash,
+        high_power,
+        pos: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_matches_naive() {
+        let text = b"aabaabaaabaabaabaab";
+        for pattern in &[&b"a"[..], b"aab", b"aabaab", b"baaab", b"abc", b"aabaabaaabaabaabaab", b"aabaabaaabaabaabaabx"] {
+            let expected: Vec<usize> = (0..text.len())
+                .filter(|&i| text[i..].starts_with(pattern))
+                .collect();
+            assert_eq!(rabin_karp_find_all(text, pattern).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn long_periodic() {
+        let text: Vec<u32> = (0..500).map(|i| i % 7).collect();
+        let pattern = &text[3..40];
+        let expected: Vec<usize> = (0..text.len())
+            .filter(|&i| text[i..].starts_with(pattern))
+            .collect();
+        assert_eq!(rabin_karp_find_all(&text, pattern).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(rabin_karp_find_all(b"ab", b"").collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
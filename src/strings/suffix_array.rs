@@ -0,0 +1,365 @@
+use search::binary_first_by;
+
+//stable counting sort of positions by their ranks
+fn counting_sort_by_rank(positions: &[usize], rank: &[usize], classes: usize) -> Vec<usize> {
+    let mut starts = vec![0usize; classes + 1];
+    for &p in positions {
+        starts[rank[p] + 1] += 1;
+    }
+    for i in 1..starts.len() {
+        starts[i] += starts[i - 1];
+    }
+    let mut sorted = vec![0; positions.len()];
+    for &p in positions {
+        sorted[starts[rank[p]]] = p;
+        starts[rank[p]] += 1;
+    }
+    sorted
+}
+
+/**
+Builds the suffix array of the sequence.
+
+The suffix array contains starting positions of all suffixes of the sequence, sorted
+lexicographically. It is built using prefix doubling: suffixes are sorted by their first
+1, 2, 4, 8... elements, every round uses counting sort on ranks from the previous round.
+
+**More:** <https://en.wikipedia.org/wiki/Suffix_array>
+
+# Complexity
+
+- Processing complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::suffix_array;
+
+fn main(){
+    //suffixes in order: a, ana, anana, banana, na, nana
+    assert_eq!(suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+}
+```
+*/
+pub fn suffix_array<T>(s: &[T]) -> Vec<usize>
+    where
+        T: Ord,
+{
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_by(|&a, &b| s[a].cmp(&s[b]));
+    //rank of every suffix according to the already sorted prefixes
+    let mut rank = vec![0usize; n];
+    for i in 1..n {
+        rank[sa[i]] = rank[sa[i - 1]] + if s[sa[i]] == s[sa[i - 1]] { 0 } else { 1 };
+    }
+    let mut classes = if n == 0 { 0 } else { rank[sa[n - 1]] + 1 };
+    let mut k = 1;
+    let mut new_rank = vec![0usize; n];
+    while classes < n {
+        //sort by the second half: suffixes without it go first, then the previous order
+        let by_second: Vec<usize> = (n - k..n)
+            .chain(sa.iter().filter(|&&p| p >= k).map(|&p| p - k))
+            .collect();
+        //stable sort by the first half
+        sa = counting_sort_by_rank(&by_second, &rank, classes);
+        let key = |p: usize| (rank[p], if p + k < n { Some(rank[p + k]) } else { None });
+        new_rank[sa[0]] = 0;
+        for i in 1..n {
+            new_rank[sa[i]] = new_rank[sa[i - 1]] + if key(sa[i]) == key(sa[i - 1]) { 0 } else { 1 };
+        }
+        classes = new_rank[sa[n - 1]] + 1;
+        rank.copy_from_slice(&new_rank);
+        k *= 2;
+    }
+    sa
+}
+
+/**
+Builds the longest common prefix array using the Kasai's algorithm.
+
+The value at index i is the length of the longest common prefix of suffixes ```sa[i-1]```
+and ```sa[i]```. The value at index 0 is 0.
+
+**More:** <https://en.wikipedia.org/wiki/LCP_array>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::{suffix_array, lcp_array};
+
+fn main(){
+    let sa = suffix_array(b"banana");
+    assert_eq!(lcp_array(b"banana", &sa), vec![0, 1, 3, 0, 0, 2]);
+}
+```
+*/
+pub fn lcp_array<T>(s: &[T], sa: &[usize]) -> Vec<usize>
+    where
+        T: Eq,
+{
+    let n = s.len();
+    let mut rank = vec![0usize; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0usize; n];
+    //the common prefix shrinks by at most one when moving to the next suffix
+    let mut h = 0usize;
+    for p in 0..n {
+        if rank[p] == 0 {
+            h = 0;
+            continue;
+        }
+        let q = sa[rank[p] - 1];
+        while p + h < n && q + h < n && s[p + h] == s[q + h] {
+            h += 1;
+        }
+        lcp[rank[p]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/**
+Suffix array with the LCP array and queries built on top of them.
+
+# Complexity
+
+- Creation complexity: O(n*log(n))
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::SuffixArray;
+
+fn main(){
+    let text = b"abracadabra";
+    let sa = SuffixArray::new(text);
+    let mut found = sa.find_all(b"abra").to_vec();
+    found.sort();
+    assert_eq!(found, vec![0, 7]);
+    assert_eq!(sa.longest_repeated_substring(), (0, 4));
+    assert_eq!(SuffixArray::new(b"aaa").distinct_substrings(), 3);
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct SuffixArray<'a, T: 'a> {
+    text: &'a [T],
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a, T> SuffixArray<'a, T> where T: Ord {
+    /**
+    Builds the suffix array and the LCP array of the given sequence.
+
+    **Complexity:** O(n*log(n))
+    */
+    pub fn new(text: &'a [T]) -> Self {
+        let sa = suffix_array(text);
+        let lcp = lcp_array(text, &sa);
+        Self { text, sa, lcp }
+    }
+
+    /**
+    Returns starting positions of suffixes in the lexicographical order.
+    */
+    pub fn suffixes(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /**
+    Returns the LCP array, see ```lcp_array()```.
+    */
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /**
+    Finds all positions where the pattern occurs.
+
+    Positions are returned in the lexicographical order of suffixes, not in the increasing order.
+    An empty pattern occurs at every position.
+
+    **Complexity:** O(m*log(n))
+    */
+    pub fn find_all(&self, pattern: &[T]) -> &[usize] {
+        let text = self.text;
+        let m = pattern.len();
+        let prefix = |p: usize| &text[p..text.len().min(p + m)];
+        let from = binary_first_by(&self.sa, |&p| prefix(p) >= pattern).unwrap_or(self.sa.len());
+        let to = binary_first_by(&self.sa, |&p| prefix(p) > pattern).unwrap_or(self.sa.len());
+        &self.sa[from..to]
+    }
+
+    /**
+    Counts occurrences of the pattern.
+
+    **Complexity:** O(m*log(n))
+    */
+    pub fn count(&self, pattern: &[T]) -> usize {
+        self.find_all(pattern).len()
+    }
+
+    /**
+    Checks if the pattern occurs in the sequence.
+
+    **Complexity:** O(m*log(n))
+    */
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        !self.find_all(pattern).is_empty()
+    }
+
+    /**
+    Counts distinct non-empty substrings of the sequence.
+
+    **Complexity:** O(n)
+    */
+    pub fn distinct_substrings(&self) -> usize {
+        let n = self.text.len();
+        n * (n + 1) / 2 - self.lcp.iter().sum::<usize>()
+    }
+
+    /**
+    Finds the longest substring that occurs at least twice (occurrences may overlap).
+
+    Returns the range ```(from, to)``` of one of its occurrences, ```(0, 0)``` if no element repeats.
+
+    **Complexity:** O(n)
+    */
+    pub fn longest_repeated_substring(&self) -> (usize, usize) {
+        let mut best = (0, 0);
+        for (i, &len) in self.lcp.iter().enumerate() {
+            if len > best.1 - best.0 {
+                best = (self.sa[i], self.sa[i] + len);
+            }
+        }
+        best
+    }
+}
+
+/**
+Finds the longest common substring of two sequences.
+
+Returns ranges ```(from, to)``` of the substring in both sequences, ```((0, 0), (0, 0))```
+if sequences have no common elements. Both sequences are joined with a unique separator and
+the longest common prefix of adjacent suffixes coming from different sequences is found.
+
+**More:** <https://en.wikipedia.org/wiki/Longest_common_substring_problem>
+
+# Complexity
+
+- Processing complexity: O((n+m)*log(n+m))
+- Memory complexity: O(n+m)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::longest_common_substring;
+
+fn main(){
+    let (a, b) = longest_common_substring(b"xabxac", b"abcabxabcd");
+    assert_eq!(a, (1, 5));
+    assert_eq!(b, (3, 7));
+}
+```
+*/
+pub fn longest_common_substring<T>(a: &[T], b: &[T]) -> ((usize, usize), (usize, usize))
+    where
+        T: Ord,
+{
+    //None is the separator, it is unique so common prefixes never cross it
+    let joined: Vec<Option<&T>> = a.iter().map(Some)
+        .chain(Some(None))
+        .chain(b.iter().map(Some))
+        .collect();
+    let sa = suffix_array(&joined);
+    let lcp = lcp_array(&joined, &sa);
+    let in_a = |p: usize| p < a.len();
+    let mut best_len = 0;
+    let mut best = ((0, 0), (0, 0));
+    for i in 1..sa.len() {
+        let (p, q) = (sa[i - 1], sa[i]);
+        if lcp[i] > best_len && in_a(p) != in_a(q) {
+            best_len = lcp[i];
+            let (pa, pb) = if in_a(p) { (p, q) } else { (q, p) };
+            let pb = pb - a.len() - 1;
+            best = ((pa, pa + best_len), (pb, pb + best_len));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_sa(s: &[u8]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..s.len()).collect();
+        sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+        sa
+    }
+
+    #[test]
+    fn matches_naive() {
+        for s in &[&b""[..], b"a", b"aaaaaaa", b"mississippi", b"abcabcabcabd", b"zyxwvutsrqp", b"abaababaabaababaababa"] {
+            let sa = suffix_array(s);
+            assert_eq!(sa, naive_sa(s));
+            let lcp = lcp_array(s, &sa);
+            for i in 1..s.len() {
+                let expected = s[sa[i - 1]..].iter().zip(&s[sa[i]..]).take_while(|&(a, b)| a == b).count();
+                assert_eq!(lcp[i], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn distinct_substrings() {
+        for s in &[&b""[..], b"a", b"abab", b"mississippi"] {
+            let mut all: Vec<&[u8]> = (0..s.len()).flat_map(|i| (i + 1..s.len() + 1).map(move |j| &s[i..j])).collect();
+            all.sort();
+            all.dedup();
+            assert_eq!(SuffixArray::new(s).distinct_substrings(), all.len());
+        }
+    }
+
+    #[test]
+    fn find_all() {
+        let text = b"mississippi";
+        let sa = SuffixArray::new(text);
+        let mut found = sa.find_all(b"ssi").to_vec();
+        found.sort();
+        assert_eq!(found, vec![2, 5]);
+        assert_eq!(sa.count(b"i"), 4);
+        assert_eq!(sa.count(b""), 11);
+        assert!(!sa.contains(b"spa"));
+        assert!(!sa.contains(b"mississippis"));
+        assert!(sa.contains(b"mississippi"));
+    }
+
+    #[test]
+    fn longest_repeated() {
+        assert_eq!(SuffixArray::new(b"abc").longest_repeated_substring(), (0, 0));
+        let text = b"mississippi";
+        let (from, to) = SuffixArray::new(text).longest_repeated_substring();
+        assert_eq!(&text[from..to], b"issi");
+    }
+
+    #[test]
+    fn longest_common() {
+        assert_eq!(longest_common_substring(b"abc", b"xyz"), ((0, 0), (0, 0)));
+        let (a, b) = (b"the quick brown fox", b"a quick brown dog");
+        let ((fa, ta), (fb, tb)) = longest_common_substring(a, b);
+        assert_eq!(&a[fa..ta], b" quick brown ");
+        assert_eq!(&b[fb..tb], b" quick brown ");
+    }
+}
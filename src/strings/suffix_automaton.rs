@@ -0,0 +1,304 @@
+use fnv::FnvHashMap;
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+#[derive(Clone, Debug)]
+struct State<T> {
+    next: FnvHashMap<T, usize>,
+    //suffix link, None only for the initial state
+    link: Option<usize>,
+    //length of the longest substring ending in this state
+    len: usize,
+    //end position of the first occurrence of substrings of this state
+    first_end: usize,
+    //true for states created for a new element, false for clones
+    primary: bool,
+}
+
+impl<T> State<T> where T: Hash + Eq {
+    fn new(len: usize, first_end: usize, primary: bool) -> Self {
+        Self {
+            next: FnvHashMap::default(),
+            link: None,
+            len,
+            first_end,
+            primary,
+        }
+    }
+}
+
+/**
+The smallest automaton accepting all substrings of a sequence.
+
+The automaton is built online: elements can be appended one by one and all queries always refer to
+the sequence built so far. It allows checking if a pattern is a substring, finding its first occurrence
+and counting its occurrences in time that depends only on the length of the pattern.
+
+**More:** <https://en.wikipedia.org/wiki/Suffix_automaton>
+
+# Complexity
+
+- Append complexity: O(1) amortized
+- Query complexity: O(m)
+- Memory complexity: O(n)
+
+where n - length of the sequence, m - length of the pattern.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::SuffixAutomaton;
+
+fn main(){
+    let mut sa: SuffixAutomaton<char> = "abcbc".chars().collect();
+    assert!(sa.contains(&['c', 'b']));
+    assert_eq!(sa.count_occurrences(&['b', 'c']), 2);
+    assert_eq!(sa.first_occurrence(&['b', 'c']), Some((1, 3)));
+    //a, b, c, ab, bc, cb, abc, bcb, cbc, abcb, bcbc, abcbc
+    assert_eq!(sa.distinct_substrings(), 12);
+
+    sa.push('a');
+    assert!(sa.contains(&['c', 'a']));
+}
+```
+*/
+#[derive(Clone, Debug)]
+pub struct SuffixAutomaton<T> {
+    states: Vec<State<T>>,
+    //state of the whole sequence
+    last: usize,
+    len: usize,
+    distinct: usize,
+    //numbers of occurrences of states, calculated when needed
+    occurrences: RefCell<Option<Vec<usize>>>,
+}
+
+impl<T> SuffixAutomaton<T> where T: Hash + Eq + Clone {
+    /**
+    Creates an automaton of an empty sequence.
+    */
+    pub fn new() -> Self {
+        Self {
+            states: vec![State::new(0, 0, false)],
+            last: 0,
+            len: 0,
+            distinct: 0,
+            occurrences: RefCell::new(None),
+        }
+    }
+
+    /**
+    Appends the element to the end of the sequence.
+
+    **Complexity:** O(1) amortized
+    */
+    pub fn push(&mut self, elem: T) {
+        self.len += 1;
+        *self.occurrences.borrow_mut() = None;
+        let cur = self.states.len();
+        self.states.push(State::new(self.len, self.len, true));
+        //add transitions from all suffixes that cannot be extended yet
+        let mut p = Some(self.last);
+        while let Some(curr) = p {
+            if self.states[curr].next.contains_key(&elem) {
+                break;
+            }
+            self.states[curr].next.insert(elem.clone(), cur);
+            p = self.states[curr].link;
+        }
+        let link = match p {
+            None => 0,
+            Some(p) => {
+                let q = self.states[p].next[&elem];
+                if self.states[p].len + 1 == self.states[q].len {
+                    q
+                } else {
+                    //split q, so that the new suffix gets its own state
+                    let clone = self.states.len();
+                    let mut state = State::new(self.states[p].len + 1, self.states[q].first_end, false);
+                    state.next = self.states[q].next.clone();
+                    state.link = self.states[q].link;
+                    self.states.push(state);
+                    let mut p = Some(p);
+                    while let Some(curr) = p {
+                        match self.states[curr].next.get_mut(&elem) {
+                            Some(target) if *target == q => *target = clone,
+                            _ => break,
+                        }
+                        p = self.states[curr].link;
+                    }
+                    self.states[q].link = Some(clone);
+                    clone
+                }
+            }
+        };
+        self.states[cur].link = Some(link);
+        //every new suffix longer than the one of the link is a new distinct substring
+        self.distinct += self.states[cur].len - self.states[link].len;
+        self.last = cur;
+    }
+
+    /**
+    Returns the length of the sequence.
+    */
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /**
+    Checks if the sequence is empty.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //state reached after reading the whole pattern
+    fn walk(&self, pattern: &[T]) -> Option<usize> {
+        let mut state = 0;
+        for elem in pattern {
+            state = *self.states[state].next.get(elem)?;
+        }
+        Some(state)
+    }
+
+    /**
+    Checks if the pattern is a substring of the sequence.
+
+    **Complexity:** O(m)
+    */
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        self.walk(pattern).is_some()
+    }
+
+    /**
+    Finds the first occurrence of the pattern, returns its range ```(from, to)```.
+
+    **Complexity:** O(m)
+    */
+    pub fn first_occurrence(&self, pattern: &[T]) -> Option<(usize, usize)> {
+        self.walk(pattern).map(|state| {
+            let end = if pattern.is_empty() { 0 } else { self.states[state].first_end };
+            (end - pattern.len(), end)
+        })
+    }
+
+    /**
+    Counts (possibly overlapping) occurrences of the pattern. An empty pattern occurs n+1 times.
+
+    **Complexity:** O(m), O(n) for the first query after appending elements
+    */
+    pub fn count_occurrences(&self, pattern: &[T]) -> usize {
+        let state = match self.walk(pattern) {
+            Some(state) => state,
+            None => return 0,
+        };
+        if state == 0 {
+            return self.len + 1;
+        }
+        let mut cache = self.occurrences.borrow_mut();
+        if cache.is_none() {
+            *cache = Some(self.calculate_occurrences());
+        }
+        cache.as_ref().map_or(0, |occ| occ[state])
+    }
+
+    //every primary state is one end position, they are propagated along suffix links from the longest
+    fn calculate_occurrences(&self) -> Vec<usize> {
+        let mut occ: Vec<usize> = self.states.iter().map(|s| if s.primary { 1 } else { 0 }).collect();
+        //counting sort of states by decreasing lengths, lengths do not exceed n
+        let max_len = self.states.iter().map(|s| s.len).max().unwrap_or(0);
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); max_len + 1];
+        for state in 1..self.states.len() {
+            buckets[self.states[state].len].push(state);
+        }
+        for state in buckets.into_iter().rev().flatten() {
+            if let Some(link) = self.states[state].link {
+                occ[link] += occ[state];
+            }
+        }
+        occ
+    }
+
+    /**
+    Counts distinct non-empty substrings of the sequence.
+
+    **Complexity:** O(1)
+    */
+    pub fn distinct_substrings(&self) -> usize {
+        self.distinct
+    }
+}
+
+impl<T> Default for SuffixAutomaton<T> where T: Hash + Eq + Clone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for SuffixAutomaton<T> where T: Hash + Eq + Clone {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for SuffixAutomaton<T> where T: Hash + Eq + Clone {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut automaton = Self::new();
+        automaton.extend(iter);
+        automaton
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_count(text: &[u8], pattern: &[u8]) -> usize {
+        (0..text.len() + 1).filter(|&i| text[i..].starts_with(pattern)).count()
+    }
+
+    #[test]
+    fn all_substrings() {
+        let text = b"abaababaabaab";
+        let sa: SuffixAutomaton<u8> = text.iter().cloned().collect();
+        let mut distinct: Vec<&[u8]> = Vec::new();
+        for i in 0..text.len() {
+            for j in i + 1..text.len() + 1 {
+                let pattern = &text[i..j];
+                assert!(sa.contains(pattern));
+                assert_eq!(sa.count_occurrences(pattern), naive_count(text, pattern));
+                let first = (0..text.len()).find(|&k| text[k..].starts_with(pattern)).unwrap();
+                assert_eq!(sa.first_occurrence(pattern), Some((first, first + pattern.len())));
+                distinct.push(pattern);
+            }
+        }
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(sa.distinct_substrings(), distinct.len());
+    }
+
+    #[test]
+    fn missing() {
+        let sa: SuffixAutomaton<u8> = b"abcd".iter().cloned().collect();
+        assert!(!sa.contains(b"ac"));
+        assert_eq!(sa.count_occurrences(b"da"), 0);
+        assert_eq!(sa.first_occurrence(b"e"), None);
+    }
+
+    #[test]
+    fn online() {
+        let mut sa = SuffixAutomaton::new();
+        assert!(sa.is_empty());
+        assert_eq!(sa.count_occurrences(&[]), 1);
+        sa.extend(vec![1, 2, 1]);
+        assert_eq!(sa.count_occurrences(&[1]), 2);
+        sa.push(1);
+        assert_eq!(sa.count_occurrences(&[1]), 3);
+        assert_eq!(sa.first_occurrence(&[1, 1]), Some((2, 4)));
+        assert_eq!(sa.first_occurrence(&[]), Some((0, 0)));
+        assert_eq!(sa.len(), 4);
+    }
+}
@@ -0,0 +1,174 @@
+/**
+Calculates the Z-function of the sequence.
+
+The value at index i is the length of the longest common prefix of ```s``` and ```s[i..]```.
+By convention the value at index 0 is the length of the whole sequence.
+
+**More:** <https://cp-algorithms.com/string/z-function.html>
+
+# Complexity
+
+- Processing complexity: O(n)
+- Memory complexity: O(n)
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::z_function;
+
+fn main(){
+    assert_eq!(z_function(b"aaabaab"), vec![7, 2, 1, 0, 2, 1, 0]);
+    assert!(z_function::<char>(&[]).is_empty());
+}
+```
+*/
+pub fn z_function<T>(s: &[T]) -> Vec<usize>
+    where
+        T: Eq,
+{
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    //s[l..r] is the rightmost found segment matching a prefix of s
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        let mut k = if i < r { z[i - l].min(r - i) } else { 0 };
+        while i + k < n && s[k] == s[i + k] {
+            k += 1;
+        }
+        if i + k > r {
+            l = i;
+            r = i + k;
+        }
+        z[i] = k;
+    }
+    z
+}
+
+/**
+Iterator over positions of a pattern in a text, created by ```z_find_all()```.
+*/
+#[derive(Clone, Debug)]
+pub struct ZMatches<'a, T: 'a> {
+    text: &'a [T],
+    pattern: &'a [T],
+    z: Vec<usize>,
+    pos: usize,
+    l: usize,
+    r: usize,
+}
+
+impl<'a, T> Iterator for ZMatches<'a, T>
+    where
+        T: Eq,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let (n, m) = (self.text.len(), self.pattern.len());
+        if m == 0 {
+            //empty pattern matches at every position, including the end of the text
+            if self.pos > n {
+                return None;
+            }
+            self.pos += 1;
+            return Some(self.pos - 1);
+        }
+        while self.pos + m <= n {
+            let i = self.pos;
+            self.pos += 1;
+            //text[l..r] is the rightmost found segment matching a prefix of the pattern
+            let mut k = if i < self.r { self.z[i - self.l].min(self.r - i) } else { 0 };
+            if i + k >= self.r {
+                while k < m && self.text[i + k] == self.pattern[k] {
+                    k += 1;
+                }
+                self.l = i;
+                self.r = i + k;
+            }
+            if k == m {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+/**
+Finds all (possibly overlapping) occurrences of the pattern in the text using the Z-algorithm.
+
+Returns an iterator of positions where matches start. Only the Z-function of the pattern
+is stored, the text is matched against it without concatenating the sequences.
+An empty pattern matches at every position.
+
+**More:** <https://cp-algorithms.com/string/z-function.html>
+
+# Complexity
+
+- Processing complexity: O(n + m)
+- Memory complexity: O(m)
+
+where n - length of the text, m - length of the pattern.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::strings::z_find_all;
+
+fn main(){
+    let text: Vec<char> = "mississippi".chars().collect();
+    let pattern: Vec<char> = "issi".chars().collect();
+    assert_eq!(z_find_all(&text, &pattern).collect::<Vec<_>>(), vec![1, 4]);
+}
+```
+*/
+pub fn z_find_all<'a, T>(text: &'a [T], pattern: &'a [T]) -> ZMatches<'a, T>
+    where
+        T: Eq,
+{
+    ZMatches {
+        text,
+        pattern,
+        z: z_function(pattern),
+        pos: 0,
+        l: 0,
+        r: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_z(s: &[u8]) -> Vec<usize> {
+        (0..s.len())
+            .map(|i| s[i..].iter().zip(s).take_while(|&(a, b)| a == b).count())
+            .collect()
+    }
+
+    #[test]
+    fn z_matches_naive() {
+        for s in &[&b"a"[..], b"abacaba", b"aaaaa", b"abcabcabcab", b"aabxaabxcaabxaabxay"] {
+            assert_eq!(z_function(s), naive_z(s));
+        }
+    }
+
+    #[test]
+    fn find_matches_naive() {
+        let text = b"aabaabaaabaabaabaab";
+        for pattern in &[&b"a"[..], b"aab", b"aabaab", b"baaab", b"abc", b"aabaabaaabaabaabaab", b"aabaabaaabaabaabaabx"] {
+            let expected: Vec<usize> = (0..text.len())
+                .filter(|&i| text[i..].starts_with(pattern))
+                .collect();
+            assert_eq!(z_find_all(text, pattern).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(z_find_all(b"ab", b"").collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,76 @@
+/**
+Primitive integer types that can be used as a search domain.
+
+This trait provides just the operations needed by search algorithms that work on implicit
+integer domains instead of slices. It is implemented for all primitive integer types.
+
+# Example
+```
+extern crate algorithm;
+use algorithm::utils::Integer;
+
+fn main() {
+    assert_eq!(Integer::midpoint(-7i8, 100i8), 46);
+    assert_eq!(Integer::midpoint(250u8, 255u8), 252);
+    assert_eq!(<u8 as Integer>::max_value().checked_add(1), None);
+}
+```
+*/
+pub trait Integer: Copy + Ord {
+    ///Returns 0.
+    fn zero() -> Self;
+    ///Returns 1.
+    fn one() -> Self;
+    ///Returns the smallest value of the type.
+    fn min_value() -> Self;
+    ///Returns the greatest value of the type.
+    fn max_value() -> Self;
+    ///Adds two values, returns None on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    ///Calculates ```lo + (hi-lo)/2``` without overflow. Requires ```lo <= hi```.
+    fn midpoint(lo: Self, hi: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $u:ty),*) => {$(
+        impl Integer for $t {
+            #[inline(always)]
+            fn zero() -> Self { 0 }
+            #[inline(always)]
+            fn one() -> Self { 1 }
+            #[inline(always)]
+            fn min_value() -> Self { <$t>::MIN }
+            #[inline(always)]
+            fn max_value() -> Self { <$t>::MAX }
+            #[inline(always)]
+            fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+            #[inline(always)]
+            fn midpoint(lo: Self, hi: Self) -> Self {
+                //hi - lo always fits in the unsigned type of the same width
+                let half = (hi.wrapping_sub(lo) as $u) / 2;
+                lo.wrapping_add(half as $t)
+            }
+        }
+    )*}
+}
+
+impl_integer!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midpoint_signed() {
+        assert_eq!(Integer::midpoint(i64::MIN, i64::MAX), -1);
+        assert_eq!(Integer::midpoint(-3i32, -1i32), -2);
+        assert_eq!(Integer::midpoint(5i32, 5i32), 5);
+    }
+
+    #[test]
+    fn midpoint_unsigned() {
+        assert_eq!(Integer::midpoint(0u64, u64::MAX), u64::MAX / 2);
+        assert_eq!(Integer::midpoint(3usize, 4usize), 3);
+    }
+}
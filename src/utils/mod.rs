@@ -1,5 +1,7 @@
 mod pair_iter;
 mod partition;
+mod integer;
 
 pub use self::pair_iter::PairIterator;
-pub (crate) use self::partition::{partition, partition_rand};
\ No newline at end of file
+pub use self::integer::Integer;
+pub (crate) use self::partition::{partition, partition_rand};
@@ -1,6 +1,5 @@
 use std::iter::{IntoIterator, Iterator};
 use std::convert::From;
-use std::mem::uninitialized;
 
 /**
 Iterator over pairs in the given collection.
@@ -29,7 +28,8 @@ fn main() {
 */
 pub struct PairIterator<T, I> where T: Copy , I: Iterator<Item=T> {
     iter: I,
-    prev: T
+    //None only if the underlying iterator was empty from the beginning
+    prev: Option<T>
 }
 
 impl <T, I> PairIterator<T, I> where T: Copy , I: Iterator<Item=T> {
@@ -44,7 +44,7 @@ impl <T, I> PairIterator<T, I> where T: Copy , I: Iterator<Item=T> {
             None => return None
         };
         Some(Self{
-            iter, prev
+            iter, prev: Some(prev)
         })
     }
 }
@@ -53,11 +53,11 @@ impl<T, I> Iterator for  PairIterator<T, I> where T: Copy , I: Iterator<Item=T>
     type Item = (T, T);
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        let prev = self.prev?;
         match self.iter.next(){
             Some(curr) =>{
-                let tmp = self.prev;
-                self.prev = curr;
-                Some((tmp, curr))
+                self.prev = Some(curr);
+                Some((prev, curr))
             }, None => None
         }
     }
@@ -69,10 +69,7 @@ impl<T, I, J> From<J> for PairIterator<T, I>
 {
     fn from(iter: J) -> Self {
         let mut iter = iter.into_iter();
-        let prev = match iter.next() {
-            Some(val) => val,
-            None => unsafe{uninitialized()} //this is safe - prev won't be used anyway
-        };
+        let prev = iter.next();
         Self{
             iter, prev
         }
@@ -111,4 +108,11 @@ mod tests {
         let mut iter = PairIterator::from(&incr);
         assert!(iter.all(|(a,b)|a<b))
     }
+
+    #[test]
+    fn empty(){
+        let arr: [i32; 0] = [];
+        let mut iter = PairIterator::from(&arr);
+        assert_eq!(iter.next(), None);
+    }
 }
\ No newline at end of file
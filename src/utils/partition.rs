@@ -1,4 +1,4 @@
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
 #[inline]
 pub fn partition<T, F>(arr: &mut [T], mut is_ordered: F) -> usize
@@ -19,11 +19,12 @@ pub fn partition<T, F>(arr: &mut [T], mut is_ordered: F) -> usize
 }
 
 #[inline]
-pub fn partition_rand<T, F>(arr: &mut [T], mut is_ordered: F) -> usize
+pub fn partition_rand<T, F, R>(arr: &mut [T], mut is_ordered: F, rng: &mut R) -> usize
     where
-        F: FnMut(&T, &T) -> bool
+        F: FnMut(&T, &T) -> bool,
+        R: Rng
 {
-    let pivot_idx = thread_rng().gen_range(0, arr.len());
+    let pivot_idx = rng.gen_range(0, arr.len());
     let last_idx = arr.len() - 1;
     arr.swap(pivot_idx, last_idx);
     let mut store_idx = 0;
@@ -72,4 +73,14 @@ mod tests {
         assert_eq!(arr, [3, 4, 8, 3, 9]);
         assert_eq!(idx, 4)
     }
+
+    #[test]
+    fn partition_rand_seeded() {
+        use rand::{SeedableRng, XorShiftRng};
+        let mut arr = [8, 4, 1, 2, 8, 9, 4, 6, 6, 5];
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let idx = partition_rand(&mut arr, |a,b| a<b, &mut rng);
+        assert!(arr[..idx].iter().all(|e| *e < arr[idx]));
+        assert!(arr[idx+1..].iter().all(|e| *e >= arr[idx]));
+    }
 }
\ No newline at end of file